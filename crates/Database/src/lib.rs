@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -453,6 +453,21 @@ fn main() -> MatrixResult<()> {
     println!("  Concurrent average: {}", format_duration(conc_avg));
     println!("  Average speedup: {:.2}x", avg_speedup);
 
+    // Determinant timing: the LU elimination rows run in parallel once the matrix has at least
+    // 64 rows, so the speedup grows with size (small matrices stay on the sequential path)
+    println!("\n--- Determinant Timing ---");
+    let det_size = 300;
+    let det_data: Vec<f64> = (0..(det_size * det_size))
+        .map(|i| (((i as u64 * 2_654_435_761) % 1000) as f64) / 500.0 - 1.0)
+        .collect();
+    let det_seq = Matrix::<f64>::from_vec_sequential(det_size, det_size, det_data.clone())?;
+    let det_conc = Matrix::<f64>::from_vec(det_size, det_size, det_data)?;
+
+    let (seq_det, seq_duration) = benchmark_operation("Sequential determinant", || det_seq.determinant().unwrap());
+    let (conc_det, conc_duration) = benchmark_operation("Concurrent determinant", || det_conc.determinant().unwrap());
+    println!("  {}x{} determinant: {:.6e} (sequential) vs {:.6e} (concurrent)", det_size, det_size, seq_det, conc_det);
+    println!("  Speedup: {:.2}x", seq_duration.as_nanos() as f64 / conc_duration.as_nanos() as f64);
+
     // Memory allocation timing
     println!("\n--- Matrix Creation Timing ---");
     let creation_size = 1000;
@@ -0,0 +1,88 @@
+use std::ops::Add;
+use std::sync::{Arc, Mutex, MutexGuard};
+use crate::matrix::Matrix;
+use crate::error::{MatrixError, MatrixResult};
+
+/// A matrix that can be shared across threads and mutated in place.
+///
+/// Every cell has its own lock, so threads touching different cells never block each other.
+/// Clones share the same cells.
+#[derive(Debug, Clone)]
+pub struct AtomicMatrix<T> {
+    rows: usize,
+    cols: usize,
+    cells: Arc<Vec<Mutex<T>>>,
+    concurrent: bool,
+}
+
+impl<T> AtomicMatrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync,
+{
+    pub fn new(rows: usize, cols: usize) -> MatrixResult<AtomicMatrix<T>> {
+        Ok(Self::from_matrix(Matrix::new(rows, cols)?))
+    }
+
+    pub fn from_matrix(matrix: Matrix<T>) -> AtomicMatrix<T> {
+        let (rows, cols) = matrix.dimensions();
+        let cells = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(|(i, j)| Mutex::new(matrix[(i, j)]))
+            .collect();
+
+        AtomicMatrix {
+            rows,
+            cols,
+            cells: Arc::new(cells),
+            concurrent: matrix.is_concurrent(),
+        }
+    }
+
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> MatrixResult<T> {
+        Ok(*self.lock(row, col)?)
+    }
+
+    pub fn set(&self, row: usize, col: usize, value: T) -> MatrixResult<()> {
+        *self.lock(row, col)? = value;
+        Ok(())
+    }
+
+    pub fn add_at(&self, row: usize, col: usize, value: T) -> MatrixResult<()>
+    where
+        T: Add<Output = T>,
+    {
+        let mut cell = self.lock(row, col)?;
+        *cell = *cell + value;
+        Ok(())
+    }
+
+    // Copies the current values out; the cells stay shared with any remaining clones
+    pub fn into_matrix(self) -> Matrix<T> {
+        let data = self.cells.iter()
+            .map(|cell| *cell.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+            .collect();
+        let mut matrix = Matrix::from_vec(self.rows, self.cols, data)
+            .expect("dimensions were validated when the AtomicMatrix was built");
+        matrix.set_concurrent(self.concurrent);
+        matrix
+    }
+
+    fn lock(&self, row: usize, col: usize) -> MatrixResult<MutexGuard<'_, T>> {
+        if row >= self.rows || col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds {
+                row,
+                col,
+                max_row: self.rows,
+                max_col: self.cols,
+            });
+        }
+        // A panic in another thread cannot leave a Copy value half-written, so poisoning is ignored
+        Ok(self.cells[row * self.cols + col]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+}
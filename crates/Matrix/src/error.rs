@@ -10,10 +10,14 @@ pub enum MatrixError {
     DimensionMismatch { expected: (usize, usize), actual: (usize, usize) },
     IncompatibleDimensions { op: String, dim1: (usize, usize), dim2: (usize, usize) },
     SingularMatrix,
+    NotPositiveDefinite,
     NotSquareMatrix { rows: usize, cols: usize },
     EmptyMatrix,
     DivisionByZero,
     InvalidOperation(String),
+    IoError(String),
+    ParseError(String),
+    ArithmeticOverflow { index: usize },
 }
 
 impl fmt::Display for MatrixError {
@@ -46,6 +50,9 @@ impl fmt::Display for MatrixError {
             MatrixError::SingularMatrix => {
                 write!(f, "Matrix is singular (determinant is zero)")
             }
+            MatrixError::NotPositiveDefinite => {
+                write!(f, "Matrix is not positive definite")
+            }
             MatrixError::NotSquareMatrix { rows, cols } => {
                 write!(f, "Operation requires square matrix, got {}x{}", rows, cols)
             }
@@ -58,6 +65,15 @@ impl fmt::Display for MatrixError {
             MatrixError::InvalidOperation(msg) => {
                 write!(f, "Invalid operation: {}", msg)
             }
+            MatrixError::IoError(msg) => {
+                write!(f, "I/O error: {}", msg)
+            }
+            MatrixError::ParseError(msg) => {
+                write!(f, "Parse error: {}", msg)
+            }
+            MatrixError::ArithmeticOverflow { index } => {
+                write!(f, "Arithmetic overflow at element {}", index)
+            }
         }
     }
 }
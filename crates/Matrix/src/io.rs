@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::Path;
+use crate::matrix::Matrix;
+use crate::error::{MatrixError, MatrixResult};
+
+// CSV import/export for floating point matrices
+impl Matrix<f64> {
+    pub fn from_csv(path: &Path) -> MatrixResult<Matrix<f64>> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| MatrixError::IoError(format!("{}: {}", path.display(), e)))?;
+
+        let mut data = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for (line_idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut row_len = 0;
+            for field in line.split(',') {
+                let field = field.trim();
+                let value = field.parse::<f64>().map_err(|_| {
+                    MatrixError::ParseError(format!("invalid number '{}' on line {}", field, line_idx + 1))
+                })?;
+                data.push(value);
+                row_len += 1;
+            }
+
+            if rows == 0 {
+                cols = row_len;
+            } else if row_len != cols {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (1, cols),
+                    actual: (1, row_len),
+                });
+            }
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        Matrix::from_vec(rows, cols, data)
+    }
+
+    pub fn to_csv(&self, path: &Path) -> MatrixResult<()> {
+        let mut contents = String::new();
+        for i in 0..self.rows() {
+            let row = self.row(i)?;
+            let line = row.iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+
+        fs::write(path, contents)
+            .map_err(|e| MatrixError::IoError(format!("{}: {}", path.display(), e)))
+    }
+}
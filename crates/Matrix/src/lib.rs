@@ -1,8 +1,15 @@
 mod matrix;
 mod error;
+mod io;
+mod atomic;
+mod pool;
+mod sparse;
 
 pub use matrix::*;
 pub use error::{MatrixError, MatrixResult};
+pub use pool::with_thread_pool;
+pub use atomic::AtomicMatrix;
+pub use sparse::SparseMatrix;
 pub use std::time::Instant;
 
 #[cfg(test)]
@@ -337,7 +344,7 @@ mod tests {
             4.0, 5.0, 6.0,
             7.0, 8.0, 9.0
         ]).unwrap();
-        let det = mat.determinant().unwrap();
+        let det: f64 = mat.determinant().unwrap();
         // Use approximate comparison for floating point values
         // This matrix is singular (determinant should be 0), but due to floating point precision
         // we get a very small number instead of exactly 0
@@ -527,4 +534,884 @@ mod tests {
             Err(MatrixError::NotSquareMatrix { .. })
         ));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_add_bias_broadcast() {
+        let mat = Matrix::from_vec(3, 4, vec![
+            1, 2, 3, 4,
+            5, 6, 7, 8,
+            9, 10, 11, 12,
+        ]).unwrap();
+        let bias = Matrix::from_vec(3, 1, vec![10, 20, 30]).unwrap();
+
+        let result = mat.add_bias_broadcast(&bias).unwrap();
+        assert_eq!(result.dimensions(), (3, 4));
+        assert_eq!(result.row(0).unwrap(), vec![11, 12, 13, 14]);
+        assert_eq!(result.row(1).unwrap(), vec![25, 26, 27, 28]);
+        assert_eq!(result.row(2).unwrap(), vec![39, 40, 41, 42]);
+
+        let mut mat_seq = mat.clone();
+        mat_seq.set_concurrent(false);
+        let mut bias_seq = bias.clone();
+        bias_seq.set_concurrent(false);
+        let result_seq = mat_seq.add_bias_broadcast(&bias_seq).unwrap();
+        assert!(!result_seq.is_concurrent());
+        for i in 0..3 {
+            assert_eq!(result_seq.row(i).unwrap(), result.row(i).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_add_bias_broadcast_dimension_mismatch() {
+        let mat = Matrix::<i32>::new(3, 4).unwrap();
+        let wrong_rows = Matrix::<i32>::new(2, 1).unwrap();
+        let not_column = Matrix::<i32>::new(3, 2).unwrap();
+
+        assert!(matches!(
+            mat.add_bias_broadcast(&wrong_rows),
+            Err(MatrixError::IncompatibleDimensions { .. })
+        ));
+        assert!(matches!(
+            mat.add_bias_broadcast(&not_column),
+            Err(MatrixError::IncompatibleDimensions { .. })
+        ));
+    }
+
+    #[test]
+    fn test_add_assign_broadcast() {
+        let mut mat = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let bias = Matrix::from_vec(2, 1, vec![100, 200]).unwrap();
+        let expected = mat.add_bias_broadcast(&bias).unwrap();
+
+        mat.add_assign_broadcast(&bias).unwrap();
+        for i in 0..2 {
+            assert_eq!(mat.row(i).unwrap(), expected.row(i).unwrap());
+        }
+
+        let wrong = Matrix::<i32>::new(3, 1).unwrap();
+        assert!(mat.add_assign_broadcast(&wrong).is_err());
+    }
+
+    #[test]
+    fn test_matrix_multiplication_by_reference() {
+        let mat1 = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let mat2 = Matrix::from_vec(3, 2, vec![7, 8, 9, 10, 11, 12]).unwrap();
+
+        let result = (&mat1 * &mat2).unwrap();
+        assert_eq!(result.row(0).unwrap(), vec![58, 64]);
+        assert_eq!(result.row(1).unwrap(), vec![139, 154]);
+
+        // Operands are still usable afterwards
+        assert!((&mat2 * &mat1).is_ok());
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("matrix_test_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let path = temp_path("round_trip.csv");
+        let mat = Matrix::from_vec(3, 3, vec![
+            1.5, -2.25, 3.0,
+            0.1, 1e-8, 42.0,
+            -7.125, 8.0, 1.0 / 3.0,
+        ]).unwrap();
+
+        mat.to_csv(&path).unwrap();
+        let loaded = Matrix::from_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.dimensions(), (3, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((loaded[(i, j)] - mat[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_csv_ragged_rows() {
+        let path = temp_path("ragged.csv");
+        std::fs::write(&path, "1,2,3\n4,5\n").unwrap();
+        let result = Matrix::from_csv(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            result,
+            Err(MatrixError::DimensionMismatch { expected: (1, 3), actual: (1, 2) })
+        ));
+    }
+
+    #[test]
+    fn test_csv_invalid_input() {
+        let path = temp_path("invalid.csv");
+        std::fs::write(&path, "1, 2\n3, abc\n").unwrap();
+        let result = Matrix::from_csv(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(MatrixError::ParseError(_))));
+
+        let missing = temp_path("does_not_exist.csv");
+        assert!(matches!(Matrix::from_csv(&missing), Err(MatrixError::IoError(_))));
+    }
+
+    #[test]
+    fn test_with_thread_pool_bounds_parallel_ops() {
+        let n = 200;
+        let a = Matrix::from_vec(n, n, (0..n * n).map(|i| (i % 17) as f64).collect()).unwrap();
+        let b = Matrix::from_vec(n, n, (0..n * n).map(|i| (i % 13) as f64 - 6.0).collect()).unwrap();
+
+        let (threads, pooled) = with_thread_pool(2, || {
+            (rayon::current_num_threads(), a.matrix_multiply(&b).unwrap())
+        });
+
+        assert_eq!(threads, 2);
+        for i in 0..n {
+            let row = a.row(i).unwrap();
+            for j in 0..n {
+                let expected: f64 = row.iter().zip(b.col(j).unwrap()).map(|(x, y)| x * y).sum();
+                assert_eq!(*pooled.get(i, j).unwrap(), expected);
+            }
+        }
+    }
+
+    fn assert_matrix_close(actual: &Matrix<f64>, expected: &Matrix<f64>, tol: f64) {
+        assert_eq!(actual.dimensions(), expected.dimensions());
+        for i in 0..actual.rows() {
+            for j in 0..actual.cols() {
+                let (a, e) = (actual[(i, j)], expected[(i, j)]);
+                assert!((a - e).abs() < tol, "({}, {}): {} vs {}", i, j, a, e);
+            }
+        }
+    }
+
+    fn assert_valid_qr(a: &Matrix<f64>) {
+        let (q, r) = a.qr_decomposition().unwrap();
+        let n = a.cols();
+        assert_eq!(q.dimensions(), (a.rows(), n));
+        assert_eq!(r.dimensions(), (n, n));
+
+        let qtq = q.transpose().unwrap().matrix_multiply(&q).unwrap();
+        assert_matrix_close(&qtq, &Matrix::identity(n).unwrap(), 1e-10);
+        assert_matrix_close(&q.matrix_multiply(&r).unwrap(), a, 1e-10);
+        for i in 0..n {
+            for j in 0..i {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_decomposition_square() {
+        let a = Matrix::from_vec(3, 3, vec![12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0]).unwrap();
+        assert_valid_qr(&a);
+    }
+
+    #[test]
+    fn test_qr_decomposition_tall() {
+        let a = Matrix::from_vec(4, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 9.0]).unwrap();
+        assert_valid_qr(&a);
+
+        let wide = Matrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert!(wide.qr_decomposition().is_err());
+    }
+
+    #[test]
+    fn test_qr_decomposition_rejects_dependent_columns() {
+        // The second column is 0.1 times the first, which leaves only rounding noise after projection
+        let a = Matrix::from_vec(3, 2, vec![0.3, 0.03, 0.7, 0.07, 1.1, 0.11]).unwrap();
+        assert!(matches!(a.qr_decomposition(), Err(MatrixError::SingularMatrix)));
+        assert!(matches!(Matrix::<f64>::zeros(2, 2).unwrap().qr_decomposition(), Err(MatrixError::SingularMatrix)));
+    }
+
+    #[test]
+    fn test_power_iteration_diagonal() {
+        let a: Matrix<f64> = Matrix::from_vec(3, 3, vec![2.0, 0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+        let (eigenvalue, eigenvector) = a.power_iteration(1000, 1e-12).unwrap();
+
+        assert!((eigenvalue - 5.0).abs() < 1e-9);
+        assert_eq!(eigenvector.dimensions(), (3, 1));
+        assert!((eigenvector[(1, 0)].abs() - 1.0).abs() < 1e-6);
+        assert!(eigenvector[(0, 0)].abs() < 1e-5);
+        assert!(eigenvector[(2, 0)].abs() < 1e-5);
+
+        let rect = Matrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert!(rect.power_iteration(10, 1e-6).is_err());
+    }
+
+    #[test]
+    fn test_power_iteration_first_estimate_zero() {
+        // 6^3 = 3^3 + 4^3 + 5^3, so the first Rayleigh quotient from the all-ones start is zero
+        let mut data = vec![0.0; 16];
+        for (i, d) in [6.0, -3.0, -4.0, -5.0].into_iter().enumerate() {
+            data[i * 4 + i] = d;
+        }
+        let a: Matrix<f64> = Matrix::from_vec(4, 4, data).unwrap();
+        let (eigenvalue, _) = a.power_iteration(1000, 1e-9).unwrap();
+        assert!((eigenvalue - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_reduce_product_matches_sequential_fold() {
+        let data: Vec<i64> = (1..=12).map(|i| (i % 4) + 1).collect();
+        let expected = data.iter().product::<i64>();
+
+        let concurrent = Matrix::from_vec(3, 4, data.clone()).unwrap();
+        let sequential = Matrix::from_vec_sequential(3, 4, data).unwrap();
+
+        assert_eq!(concurrent.reduce(1, |a, b| a * b), expected);
+        assert_eq!(sequential.reduce(1, |a, b| a * b), expected);
+        assert_eq!(sequential.reduce(i64::MIN, i64::max), 4);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let data = vec![-5.0, -0.5, 0.0, 2.5, 6.0, 10.0];
+        let expected = [0.0, 0.0, 0.0, 2.5, 6.0, 6.0];
+
+        let concurrent = Matrix::from_vec(2, 3, data.clone()).unwrap();
+        let sequential = Matrix::from_vec_sequential(2, 3, data).unwrap();
+        for mat in [concurrent, sequential] {
+            let clamped = mat.clamp(0.0, 6.0);
+            assert_eq!(clamped.dimensions(), (2, 3));
+            assert_eq!(clamped.is_concurrent(), mat.is_concurrent());
+            for (i, &e) in expected.iter().enumerate() {
+                assert_eq!(clamped[(i / 3, i % 3)], e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinant_exact_integers() {
+        let a = Matrix::from_vec(3, 3, vec![2i64, -3, 1, 2, 0, -1, 1, 4, 5]).unwrap();
+        assert_eq!(a.determinant_exact().unwrap(), 49);
+
+        // Zero leading pivot forces a row swap
+        let b = Matrix::from_vec(3, 3, vec![0i64, 1, 2, 1, 0, 3, 4, -3, 8]).unwrap();
+        assert_eq!(b.determinant_exact().unwrap(), -2);
+
+        let singular = Matrix::from_vec(3, 3, vec![1i64, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+        assert_eq!(singular.determinant_exact().unwrap(), 0);
+
+        assert_eq!(Matrix::from_vec(1, 1, vec![-7i32]).unwrap().determinant_exact().unwrap(), -7);
+        assert!(matches!(
+            Matrix::<i64>::new(2, 3).unwrap().determinant_exact(),
+            Err(MatrixError::NotSquareMatrix { rows: 2, cols: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_sub_col_and_row_vector() {
+        let a = Matrix::from_vec(3, 4, (1..=12).collect()).unwrap();
+        let col = Matrix::from_vec(3, 1, vec![1, 5, 9]).unwrap();
+
+        let shifted = a.sub_col_vector(&col).unwrap();
+        assert_eq!(shifted.row(0).unwrap(), vec![0, 1, 2, 3]);
+        assert_eq!(shifted.row(1).unwrap(), vec![0, 1, 2, 3]);
+        assert_eq!(shifted.row(2).unwrap(), vec![0, 1, 2, 3]);
+
+        let row = Matrix::from_vec_sequential(1, 4, vec![9, 10, 11, 12]).unwrap();
+        let mut a_seq = a.clone();
+        a_seq.set_concurrent(false);
+        let shifted = a_seq.sub_row_vector(&row).unwrap();
+        assert_eq!(shifted.col(0).unwrap(), vec![-8, -4, 0]);
+        assert_eq!(shifted.col(3).unwrap(), vec![-8, -4, 0]);
+
+        assert!(a.sub_col_vector(&row).is_err());
+        assert!(a.sub_row_vector(&col).is_err());
+    }
+
+    #[test]
+    fn test_checked_scalar_mul_detects_overflow() {
+        let ok = Matrix::from_vec(2, 2, vec![1, -2, 3, 4]).unwrap();
+        let scaled = ok.checked_scalar_mul(3).unwrap();
+        assert_eq!(scaled.row(0).unwrap(), vec![3, -6]);
+        assert_eq!(scaled.row(1).unwrap(), vec![9, 12]);
+
+        let near_max = Matrix::from_vec_sequential(2, 2, vec![1, 2, i32::MAX / 2 + 1, 4]).unwrap();
+        let err = near_max.checked_scalar_mul(2).unwrap_err();
+        assert_eq!(err, MatrixError::ArithmeticOverflow { index: 2 });
+        assert_eq!(err.to_string(), "Arithmetic overflow at element 2");
+
+        let mut concurrent = near_max.clone();
+        concurrent.set_concurrent(true);
+        assert!(matches!(concurrent.checked_scalar_mul(2), Err(MatrixError::ArithmeticOverflow { .. })));
+    }
+
+    #[test]
+    fn test_atomic_matrix_concurrent_increments() {
+        let shared = AtomicMatrix::<i64>::new(2, 3).unwrap();
+
+        let handles: Vec<_> = (0..6)
+            .map(|t| {
+                let shared = shared.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        shared.add_at(t / 3, t % 3, 1).unwrap();
+                        // Every thread also hammers a common cell
+                        shared.add_at(0, 0, 1).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(shared.get(2, 0).is_err());
+        let result = shared.into_matrix();
+        assert_eq!(result.row(0).unwrap(), vec![7000, 1000, 1000]);
+        assert_eq!(result.row(1).unwrap(), vec![1000, 1000, 1000]);
+    }
+
+    #[test]
+    fn test_augment_and_rref_solve() {
+        // 2x + y = 5, x - y = 1  =>  x = 2, y = 1
+        let a = Matrix::from_vec(2, 2, vec![2.0, 1.0, 1.0, -1.0]).unwrap();
+        let b = Matrix::from_vec(2, 1, vec![5.0, 1.0]).unwrap();
+
+        let augmented = a.augment(&b).unwrap();
+        assert_eq!(augmented.dimensions(), (2, 3));
+        assert_eq!(augmented.row(0).unwrap(), vec![2.0, 1.0, 5.0]);
+
+        let reduced = augmented.rref().unwrap();
+        let expected = Matrix::from_vec(2, 3, vec![1.0, 0.0, 2.0, 0.0, 1.0, 1.0]).unwrap();
+        assert_matrix_close(&reduced, &expected, 1e-12);
+
+        assert!(a.augment(&Matrix::<f64>::new(3, 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_rref_rank_deficient() {
+        let m = Matrix::from_vec(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]).unwrap();
+        let expected = Matrix::from_vec(3, 3, vec![1.0, 0.0, -1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0]).unwrap();
+        assert_matrix_close(&m.rref().unwrap(), &expected, 1e-12);
+    }
+
+    #[test]
+    fn test_masks_and_select() {
+        let a = Matrix::from_vec(2, 2, vec![-1.0, 2.0, 0.0, 3.5]).unwrap();
+        let zeros = Matrix::zeros(2, 2).unwrap();
+
+        let positive = a.gt_scalar(0.0);
+        assert_eq!(positive.dimensions(), (2, 2));
+        assert_eq!(positive.row(0).unwrap(), vec![false, true]);
+        assert_eq!(positive.row(1).unwrap(), vec![false, true]);
+        assert_eq!(a.le_scalar(0.0).row(1).unwrap(), vec![true, false]);
+        assert_eq!(a.eq_scalar(0.0).row(1).unwrap(), vec![true, false]);
+        assert_eq!(a.lt_scalar(2.0).row(0).unwrap(), vec![true, false]);
+        assert_eq!(a.ge_scalar(2.0).row(0).unwrap(), vec![false, true]);
+
+        // Masked ReLU
+        let relu = a.select(&positive, &zeros).unwrap();
+        assert_eq!(relu.row(0).unwrap(), vec![0.0, 2.0]);
+        assert_eq!(relu.row(1).unwrap(), vec![0.0, 3.5]);
+
+        assert!(a.select(&Matrix::new(1, 2).unwrap(), &zeros).is_err());
+
+        // A concurrent mask alone makes the result concurrent
+        let seq = Matrix::from_vec_sequential(2, 2, vec![-1.0, 2.0, 0.0, 3.5]).unwrap();
+        let seq_zeros = Matrix::from_vec_sequential(2, 2, vec![0.0; 4]).unwrap();
+        assert!(!seq.select(&seq.gt_scalar(0.0), &seq_zeros).unwrap().is_concurrent());
+        assert!(seq.select(&positive, &seq_zeros).unwrap().is_concurrent());
+    }
+
+    #[test]
+    fn test_cofactor_matrix_errors_instead_of_panicking() {
+        let data = vec![2.0, 0.0, 1.0, 3.0, 1.0, 4.0, 0.0, 2.0, 0.0, 1.0, 5.0, 1.0, 3.0, 0.0, 2.0, 6.0];
+        let concurrent = Matrix::from_vec(4, 4, data.clone()).unwrap();
+        let sequential = Matrix::from_vec_sequential(4, 4, data).unwrap();
+
+        let c1 = concurrent.cofactor_matrix().unwrap();
+        let c2 = sequential.cofactor_matrix().unwrap();
+        assert_matrix_close(&c1, &c2, 1e-9);
+
+        // Laplace expansion along the first row reproduces the determinant
+        let det: f64 = (0..4).map(|j| concurrent[(0, j)] * c1[(0, j)]).sum();
+        assert!((det - concurrent.determinant().unwrap()).abs() < 1e-9);
+
+        // A 1x1 has no minors: both paths must report it rather than panic
+        assert!(Matrix::from_vec(1, 1, vec![5.0]).unwrap().cofactor_matrix().is_err());
+        assert!(Matrix::from_vec_sequential(1, 1, vec![5.0]).unwrap().cofactor_matrix().is_err());
+    }
+
+    #[test]
+    fn test_weighted_trace() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let concurrent = Matrix::from_vec(3, 3, data.clone()).unwrap();
+        let sequential = Matrix::from_vec_sequential(3, 3, data).unwrap();
+
+        // 2*1 + 0*5 + (-1)*9
+        assert_eq!(concurrent.weighted_trace(&[2, 0, -1]).unwrap(), -7);
+        assert_eq!(sequential.weighted_trace(&[2, 0, -1]).unwrap(), -7);
+        assert_eq!(sequential.weighted_trace(&[1, 1, 1]).unwrap(), sequential.trace().unwrap());
+
+        assert!(matches!(concurrent.weighted_trace(&[1, 2]), Err(MatrixError::DimensionMismatch { .. })));
+        let rect = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert!(matches!(rect.weighted_trace(&[1, 1]), Err(MatrixError::NotSquareMatrix { .. })));
+    }
+
+    #[test]
+    fn test_row_and_col_sums() {
+        let data = vec![1, 2, 3, 4, 5, 6];
+        let concurrent = Matrix::from_vec(2, 3, data.clone()).unwrap();
+        let sequential = Matrix::from_vec_sequential(2, 3, data).unwrap();
+        for mat in [concurrent, sequential] {
+            assert_eq!(mat.row_sums(), vec![6, 15]);
+            assert_eq!(mat.col_sums(), vec![5, 7, 9]);
+        }
+    }
+
+    #[test]
+    fn test_col_major_round_trip() {
+        let data: Vec<i32> = (1..=6).collect();
+        let col_major = Matrix::from_vec_col_major(2, 3, data.clone()).unwrap();
+        let transposed = Matrix::from_vec(3, 2, data.clone()).unwrap().transpose().unwrap();
+
+        assert_eq!(col_major.dimensions(), (2, 3));
+        for r in 0..2 {
+            assert_eq!(col_major.row(r).unwrap(), transposed.row(r).unwrap());
+        }
+        assert_eq!(col_major.row(0).unwrap(), vec![1, 3, 5]);
+        assert_eq!(col_major.to_col_major(), data);
+        assert!(Matrix::from_vec_col_major(2, 3, vec![1, 2, 3]).is_err());
+        assert!(matches!(
+            Matrix::from_vec_col_major(2, 3, vec![1, 2, 3, 4, 5]),
+            Err(MatrixError::DimensionMismatch { expected: (2, 3), actual: (2, 2) })
+        ));
+    }
+
+    #[test]
+    fn test_flat_access() {
+        let mut mat = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(*mat.get_flat(4).unwrap(), 5);
+        mat.set_flat(5, 60).unwrap();
+        assert_eq!(mat[(1, 2)], 60);
+
+        assert_eq!(
+            mat.get_flat(6),
+            Err(MatrixError::IndexOutOfBounds { row: 2, col: 0, max_row: 2, max_col: 3 })
+        );
+        assert!(mat.set_flat(100, 0).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "(flat offset 7)")]
+    fn test_index_panic_reports_flat_offset() {
+        let mat = Matrix::<i32>::new(2, 3).unwrap();
+        let _ = mat[(2, 1)];
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for matrix of size 2x3")]
+    fn test_index_panic_with_huge_row_does_not_overflow() {
+        let mut mat = Matrix::<i32>::new(2, 3).unwrap();
+        mat[(usize::MAX, 1)] = 1;
+    }
+
+    #[test]
+    fn test_resize_grow_and_shrink() {
+        let small = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let grown = small.resize(3, 3, 9).unwrap();
+        assert_eq!(grown.dimensions(), (3, 3));
+        assert_eq!(grown.row(0).unwrap(), vec![1, 2, 9]);
+        assert_eq!(grown.row(1).unwrap(), vec![3, 4, 9]);
+        assert_eq!(grown.row(2).unwrap(), vec![9, 9, 9]);
+
+        let big = Matrix::from_vec(3, 3, (1..=9).collect()).unwrap();
+        let shrunk = big.resize(2, 2, 0).unwrap();
+        assert_eq!(shrunk.row(0).unwrap(), vec![1, 2]);
+        assert_eq!(shrunk.row(1).unwrap(), vec![4, 5]);
+
+        let mixed = big.resize(1, 4, 0).unwrap();
+        assert_eq!(mixed.row(0).unwrap(), vec![1, 2, 3, 0]);
+        assert_eq!(big.resize(0, 2, 0).unwrap_err(), MatrixError::InvalidDimensions);
+    }
+
+    #[test]
+    fn test_rows_and_cols_iter() {
+        let mat = Matrix::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let rows: Vec<&[i32]> = mat.rows_iter().collect();
+        assert_eq!(rows.len(), 3);
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(row.to_vec(), mat.row(i).unwrap());
+        }
+
+        let cols: Vec<Vec<i32>> = mat.cols_iter().collect();
+        assert_eq!(cols, vec![mat.col(0).unwrap(), mat.col(1).unwrap()]);
+
+        let row_totals: Vec<i32> = mat.rows_iter().map(|r| r.iter().sum()).collect();
+        assert_eq!(row_totals, vec![3, 7, 11]);
+    }
+
+    #[test]
+    fn test_sequential_mode_survives_chained_ops() {
+        let a = Matrix::from_vec_sequential(3, 3, vec![2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]).unwrap();
+        let b = Matrix::from_vec_sequential(3, 3, vec![1.0; 9]).unwrap();
+
+        let sum = (a.transpose().unwrap() + b.clone()).unwrap();
+        let chained = (sum * 0.5).unwrap()
+            .matrix_multiply(&a).unwrap()
+            .cofactor_matrix().unwrap()
+            .resize(2, 4, 0.0).unwrap()
+            .clamp(-10.0, 10.0);
+        assert!(!chained.is_concurrent());
+        assert!(!a.gt_scalar(1.0).is_concurrent());
+        let offsets = Matrix::from_vec_sequential(1, 6, vec![1.0; 6]).unwrap();
+        assert!(!a.augment(&b).unwrap().sub_row_vector(&offsets).unwrap().is_concurrent());
+
+        // One concurrent input makes the result concurrent
+        let concurrent = Matrix::<f64>::identity(3).unwrap();
+        assert!(concurrent.is_concurrent());
+        assert!((a + concurrent).unwrap().is_concurrent());
+    }
+
+    #[test]
+    fn test_backing_buffer_access() {
+        let data = vec![1, 2, 3, 4, 5, 6];
+        let mut mat = Matrix::from_vec(2, 3, data.clone()).unwrap();
+        assert_eq!(mat.as_slice(), &data[..]);
+
+        mat.as_mut_slice()[4] = 50;
+        assert_eq!(mat[(1, 1)], 50);
+        mat.as_mut_slice()[4] = 5;
+
+        assert_eq!(mat.into_vec(), data);
+    }
+
+    #[test]
+    fn test_normalize_rows_and_cols() {
+        let mat: Matrix<f64> = Matrix::from_vec(3, 2, vec![3.0, 4.0, 0.0, 0.0, -1.0, 1.0]).unwrap();
+        assert_eq!(mat.row_norms(), vec![5.0, 0.0, 2f64.sqrt()]);
+
+        let rows = mat.normalize_rows().unwrap();
+        assert_eq!(rows.row(1).unwrap(), vec![0.0, 0.0]);
+        for (i, norm) in rows.row_norms().into_iter().enumerate() {
+            if i != 1 {
+                assert!((norm - 1.0).abs() < 1e-12);
+            }
+        }
+        assert_eq!(rows.row(0).unwrap(), vec![0.6, 0.8]);
+
+        let mut sequential = mat.clone();
+        sequential.set_concurrent(false);
+        let cols = sequential.normalize_cols().unwrap();
+        for norm in cols.col_norms() {
+            assert!((norm - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(cols.col(0).unwrap(), vec![3.0 / 10f64.sqrt(), 0.0, -1.0 / 10f64.sqrt()]);
+
+        let zero_col: Matrix<f64> = Matrix::from_vec(2, 2, vec![0.0, 2.0, 0.0, 0.0]).unwrap();
+        assert_eq!(zero_col.normalize_cols().unwrap().col(0).unwrap(), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_quadrants_round_trip() {
+        let even = Matrix::from_vec(4, 4, (0..16).collect()).unwrap();
+        let (tl, tr, bl, br) = even.split_quadrants().unwrap();
+        assert_eq!(tl.row(1).unwrap(), vec![4, 5]);
+        assert_eq!(tr.row(0).unwrap(), vec![2, 3]);
+        assert_eq!(bl.row(0).unwrap(), vec![8, 9]);
+        assert_eq!(br.row(1).unwrap(), vec![14, 15]);
+        let rebuilt = Matrix::from_quadrants(&tl, &tr, &bl, &br).unwrap();
+        assert_eq!(rebuilt.as_slice(), even.as_slice());
+
+        let odd = Matrix::from_vec(5, 5, (0..25).collect()).unwrap();
+        let (tl, tr, bl, br) = odd.split_quadrants().unwrap();
+        assert_eq!(tl.dimensions(), (3, 3));
+        assert_eq!(tr.dimensions(), (3, 2));
+        assert_eq!(bl.dimensions(), (2, 3));
+        assert_eq!(br.dimensions(), (2, 2));
+        let rebuilt = Matrix::from_quadrants(&tl, &tr, &bl, &br).unwrap();
+        assert_eq!(rebuilt.dimensions(), (5, 5));
+        assert_eq!(rebuilt.as_slice(), odd.as_slice());
+
+        assert!(Matrix::from_quadrants(&tl, &tr, &br, &bl).is_err());
+        assert!(Matrix::from_vec(1, 3, vec![1, 2, 3]).unwrap().split_quadrants().is_err());
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let u = Matrix::from_vec(3, 1, vec![1, 2, 3]).unwrap();
+        let v = Matrix::from_vec(2, 1, vec![4, -1]).unwrap();
+        let outer = u.outer_product(&v).unwrap();
+        assert_eq!(outer.dimensions(), (3, 2));
+        assert_eq!(outer.as_slice(), &[4, -1, 8, -2, 12, -3]);
+
+        let mut sequential = u.clone();
+        sequential.set_concurrent(false);
+        let gram = sequential.outer_product(&Matrix::from_vec_sequential(3, 1, vec![1, 2, 3]).unwrap()).unwrap();
+        assert_eq!(gram.row(2).unwrap(), vec![3, 6, 9]);
+
+        let row = Matrix::from_vec(1, 2, vec![1, 2]).unwrap();
+        assert!(u.outer_product(&row).is_err());
+    }
+
+    #[test]
+    fn test_swap_cols_and_transpose_in_place() {
+        let mut mat = Matrix::from_vec(3, 3, (1..=9).collect()).unwrap();
+        mat.swap_cols(0, 2).unwrap();
+        assert_eq!(mat.as_slice(), &[3, 2, 1, 6, 5, 4, 9, 8, 7]);
+        assert_eq!(mat.swap_cols(1, 3), Err(MatrixError::InvalidColumnDimension));
+
+        let expected = mat.transpose().unwrap();
+        mat.transpose_in_place().unwrap();
+        assert_eq!(mat.as_slice(), expected.as_slice());
+
+        let mut wide = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(wide.transpose_in_place(), Err(MatrixError::NotSquareMatrix { rows: 2, cols: 3 }));
+    }
+
+    #[test]
+    fn test_det_checked_reports_singular_matrices() {
+        let singular: Matrix<f64> = Matrix::from_vec(3, 3, (1..=9).map(f64::from).collect()).unwrap();
+        assert_eq!(singular.det_checked(), Err(MatrixError::SingularMatrix));
+        assert!(singular.determinant().unwrap().abs() < 1e-9);
+
+        let regular: Matrix<f64> = Matrix::from_vec(3, 3, vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]).unwrap();
+        assert!((regular.det_checked().unwrap() - 4.0).abs() < 1e-12);
+        assert!((regular.det_checked().unwrap() - regular.determinant().unwrap()).abs() < 1e-12);
+
+        let zeros = Matrix::<f64>::zeros(2, 2).unwrap();
+        assert_eq!(zeros.det_checked(), Err(MatrixError::SingularMatrix));
+        assert!(Matrix::<f64>::zeros(2, 3).unwrap().det_checked().is_err());
+    }
+
+    #[test]
+    fn test_matrix_equality_ignores_mode() {
+        let a = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let b = Matrix::from_vec_sequential(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(a, b);
+        assert!(a.eq(&b));
+
+        let c = Matrix::from_vec(2, 2, vec![1, 2, 3, 5]).unwrap();
+        assert_ne!(a, c);
+        let reshaped = Matrix::from_vec(1, 4, vec![1, 2, 3, 4]).unwrap();
+        assert_ne!(a, reshaped);
+    }
+
+    #[test]
+    fn test_hadamard_pow() {
+        let m = Matrix::from_vec(2, 2, vec![1, -2, 3, 4]).unwrap();
+        assert_eq!(m.hadamard_pow(2), m.dot_product(&m).unwrap());
+        assert_eq!(m.hadamard_pow(3).into_vec(), vec![1, -8, 27, 64]);
+        assert_eq!(m.hadamard_pow(1), m);
+    }
+
+    #[test]
+    fn test_hadamard_pow_zero_exponent_gives_ones() {
+        let m = Matrix::from_vec(2, 2, vec![1.5, -2.0, 0.0, 4.0]).unwrap();
+        assert_eq!(m.hadamard_pow(0), Matrix::<f64>::ones(2, 2).unwrap());
+    }
+
+    #[test]
+    fn test_parallel_determinant_matches_sequential() {
+        let n = 200;
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let data: Vec<f64> = (0..n * n)
+            .map(|_| {
+                state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+                ((state >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+            })
+            .collect();
+
+        let sequential = Matrix::from_vec_sequential(n, n, data.clone()).unwrap().determinant().unwrap();
+        let concurrent = Matrix::from_vec(n, n, data).unwrap().determinant().unwrap();
+        assert!(sequential != 0.0 && sequential.is_finite());
+        assert!((sequential - concurrent).abs() <= 1e-9 * sequential.abs());
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut m = Matrix::fill(2, 3, 7).unwrap();
+        assert_eq!(m.dimensions(), (2, 3));
+        assert!(m.as_slice().iter().all(|&x| x == 7));
+
+        m.fill_mut(-1);
+        assert_eq!(m.into_vec(), vec![-1; 6]);
+        assert!(Matrix::fill(0, 3, 7).is_err());
+    }
+
+    #[test]
+    fn test_sparse_matrix_round_trip_and_multiply() {
+        let dense = Matrix::from_vec(3, 4, vec![
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            5, 0, 0, -1,
+        ]).unwrap();
+        let sparse = SparseMatrix::from_dense(&dense);
+        assert_eq!(sparse.dimensions(), (3, 4));
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.to_dense().unwrap(), dense);
+
+        let rhs = Matrix::from_vec(4, 2, vec![1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let expected = dense.matrix_multiply(&rhs).unwrap();
+        assert_eq!(sparse.sparse_dense_multiply(&rhs).unwrap(), expected);
+
+        let mut sequential = sparse.clone();
+        sequential.set_concurrent(false);
+        let rhs_sequential = Matrix::from_vec_sequential(4, 2, vec![1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let product = sequential.sparse_dense_multiply(&rhs_sequential).unwrap();
+        assert!(!product.is_concurrent());
+        assert_eq!(product, expected);
+
+        assert!(sparse.sparse_dense_multiply(&Matrix::<i32>::new(3, 2).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_variance_and_std_dev() {
+        // Elements 2, 4, 4, 4, 5, 5, 7, 9: mean 5, population variance 4
+        let m = Matrix::from_vec(2, 4, vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+        assert_eq!(m.mean(), 5.0);
+        assert_eq!(m.variance(), 4.0);
+        assert_eq!(m.std_dev(), 2.0);
+
+        let mut sequential = m.clone();
+        sequential.set_concurrent(false);
+        assert_eq!(sequential.variance(), 4.0);
+
+        // Columns (2, 5), (4, 5), (4, 7), (4, 9); rows have means 3.5 and 6.5
+        assert_eq!(m.variance_axis(0).unwrap(), vec![2.25, 0.25, 2.25, 6.25]);
+        assert_eq!(m.variance_axis(1).unwrap(), vec![0.75, 2.75]);
+        assert_eq!(m.std_dev_axis(0).unwrap(), vec![1.5, 0.5, 1.5, 2.5]);
+        assert!(m.variance_axis(2).is_err());
+    }
+
+    #[test]
+    fn test_from_rows_round_trip() {
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let m = Matrix::from_rows(rows.clone()).unwrap();
+        assert_eq!(m.dimensions(), (2, 3));
+        assert_eq!(m, Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap());
+        assert_eq!(m.to_rows(), rows);
+    }
+
+    #[test]
+    fn test_from_rows_rejects_ragged_input() {
+        assert!(matches!(
+            Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5]]),
+            Err(MatrixError::DimensionMismatch { expected: (1, 3), actual: (1, 2) })
+        ));
+        assert!(matches!(Matrix::<i32>::from_rows(Vec::new()), Err(MatrixError::EmptyMatrix)));
+        assert!(matches!(Matrix::<i32>::from_rows(vec![Vec::new()]), Err(MatrixError::InvalidDimensions)));
+    }
+
+    #[test]
+    fn test_gemm_matches_multiply_then_add() {
+        let a = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b = Matrix::from_vec(3, 2, vec![7, 8, 9, 10, 11, 12]).unwrap();
+        let c = Matrix::from_vec(2, 2, vec![1, -1, 2, -2]).unwrap();
+
+        let product = a.matrix_multiply(&b).unwrap();
+        let expected = ((product * 2).unwrap() + (c.clone() * 3).unwrap()).unwrap();
+        assert_eq!(a.gemm(&b, &c, 2, 3).unwrap(), expected);
+
+        let a_seq = Matrix::from_vec_sequential(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b_seq = Matrix::from_vec_sequential(3, 2, vec![7, 8, 9, 10, 11, 12]).unwrap();
+        let c_seq = Matrix::from_vec_sequential(2, 2, vec![1, -1, 2, -2]).unwrap();
+        let sequential = a_seq.gemm(&b_seq, &c_seq, 2, 3).unwrap();
+        assert!(!sequential.is_concurrent());
+        assert_eq!(sequential, expected);
+
+        assert!(a.gemm(&b, &Matrix::new(3, 2).unwrap(), 1, 1).is_err());
+        assert!(a.gemm(&a, &c, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_convolve2d_valid() {
+        let image = Matrix::from_vec(4, 4, vec![
+            1, 2, 3, 4,
+            5, 6, 7, 8,
+            9, 10, 11, 12,
+            13, 14, 15, 16,
+        ]).unwrap();
+        let kernel = Matrix::from_vec(2, 2, vec![1, 0, 0, -1]).unwrap();
+
+        // Every window gives top-left minus bottom-right, which is -5 everywhere
+        let output = image.convolve2d(&kernel).unwrap();
+        assert_eq!(output, Matrix::from_vec(3, 3, vec![-5; 9]).unwrap());
+
+        let box_kernel = Matrix::from_vec_sequential(2, 2, vec![1, 1, 1, 1]).unwrap();
+        let mut sequential = image.clone();
+        sequential.set_concurrent(false);
+        let sums = sequential.convolve2d(&box_kernel).unwrap();
+        assert!(!sums.is_concurrent());
+        assert_eq!(sums.row(0).unwrap(), vec![14, 18, 22]);
+        assert_eq!(sums.row(2).unwrap(), vec![46, 50, 54]);
+
+        assert!(kernel.convolve2d(&image).is_err());
+    }
+
+    #[test]
+    fn test_scalar_ops_by_reference() {
+        let m = Matrix::from_vec(2, 2, vec![2.0, -4.0, 6.0, 8.0]).unwrap();
+
+        let negated = (-&m).unwrap();
+        let doubled = (&m * 2.0).unwrap();
+        let halved = (&m / 2.0).unwrap();
+
+        assert_eq!(negated.into_vec(), vec![-2.0, 4.0, -6.0, -8.0]);
+        assert_eq!(doubled.into_vec(), vec![4.0, -8.0, 12.0, 16.0]);
+        assert_eq!(halved.into_vec(), vec![1.0, -2.0, 3.0, 4.0]);
+        assert!(matches!(&m / 0.0, Err(MatrixError::DivisionByZero)));
+
+        // `m` is still usable, and the owned operators agree
+        assert_eq!((m.clone() * 2.0).unwrap(), (&m * 2.0).unwrap());
+        assert_eq!((-m.clone()).unwrap(), (-&m).unwrap());
+    }
+
+    #[test]
+    fn test_orthogonality_check() {
+        assert!(Matrix::<f64>::identity(3).unwrap().is_orthogonal(1e-12));
+
+        let theta = 0.7f64;
+        let rotation = Matrix::from_vec(2, 2, vec![theta.cos(), -theta.sin(), theta.sin(), theta.cos()]).unwrap();
+        assert!(rotation.is_orthogonal(1e-12));
+
+        let shear = Matrix::from_vec(2, 2, vec![1.0, 1.0, 0.0, 1.0]).unwrap();
+        assert!(!shear.is_orthogonal(1e-6));
+        assert!(!Matrix::<f64>::ones(2, 3).unwrap().is_orthogonal(1e-6));
+    }
+
+    #[test]
+    fn test_positive_definite_via_cholesky() {
+        let spd = Matrix::from_vec(3, 3, vec![
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0,
+        ]).unwrap();
+        assert!(spd.is_positive_definite());
+
+        let l = spd.cholesky().unwrap();
+        assert_eq!(l, Matrix::from_vec(3, 3, vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]).unwrap());
+        assert_matrix_close(&l.matrix_multiply(&l.transpose().unwrap()).unwrap(), &spd, 1e-9);
+
+        let indefinite = Matrix::from_vec(2, 2, vec![1.0, 2.0, 2.0, 1.0]).unwrap();
+        assert!(matches!(indefinite.cholesky(), Err(MatrixError::NotPositiveDefinite)));
+        let asymmetric = Matrix::from_vec(2, 2, vec![2.0, 1.0, 0.0, 2.0]).unwrap();
+        assert!(!asymmetric.is_positive_definite());
+        assert!(!Matrix::<f64>::ones(2, 3).unwrap().is_positive_definite());
+    }
+
+    #[test]
+    fn test_f32_determinant_and_cofactors_match_f64() {
+        let values: [f64; 9] = [2.5, -1.0, 0.75, 3.0, 4.25, -2.0, 1.5, 0.5, 6.0];
+        let m64 = Matrix::from_vec(3, 3, values.to_vec()).unwrap();
+        let m32 = Matrix::from_vec(3, 3, values.iter().map(|&x| x as f32).collect()).unwrap();
+
+        let det64 = m64.determinant().unwrap();
+        let det32 = m32.determinant().unwrap();
+        assert!((det32 as f64 - det64).abs() <= 1e-5 * det64.abs(), "{} vs {}", det32, det64);
+        assert!(((m32.det_checked().unwrap() as f64) - det64).abs() <= 1e-5 * det64.abs());
+
+        let cof64 = m64.cofactor_matrix().unwrap();
+        let cof32 = m32.cofactor_matrix().unwrap();
+        for (a, b) in cof32.as_slice().iter().zip(cof64.as_slice()) {
+            assert!(((*a as f64) - b).abs() <= 1e-4 * b.abs().max(1.0));
+        }
+
+        // Rows 1 and 3 are multiples, so the rounding in f32 elimination must still read as singular
+        let singular = Matrix::from_vec(3, 3, vec![0.1f32, 0.2, 0.3, 1.0, -1.0, 2.0, 0.3, 0.6, 0.9]).unwrap();
+        assert!(matches!(singular.det_checked(), Err(MatrixError::SingularMatrix)));
+    }
+}
@@ -1,7 +1,18 @@
 use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg};
 use rayon::prelude::*;
+use num_traits::{Float, One, PrimInt, Signed};
 use crate::error::{MatrixError, MatrixResult};
 
+// Smallest matrix for which `determinant` parallelises its elimination rows; below it the
+// per-pivot rayon dispatch costs more than the row updates it splits up
+const PARALLEL_DETERMINANT_SIZE: usize = 64;
+
+// (top-left, top-right, bottom-left, bottom-right) blocks from `split_quadrants`
+pub type Quadrants<T> = (Matrix<T>, Matrix<T>, Matrix<T>, Matrix<T>);
+
+// Execution mode rule: constructors (`new`, `from_vec`, `identity`, `zeros`, ...) start
+// concurrent and the `_sequential` ones do not. Every operation's result is concurrent iff any
+// of its matrix inputs is, so a chain of operations on sequential matrices stays sequential.
 #[derive(Debug, Clone)]
 pub struct Matrix<T> {
     rows: usize,
@@ -74,6 +85,47 @@ where
         })
     }
 
+    // Row-major from nested vectors; every row must have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> MatrixResult<Matrix<T>> {
+        let cols = match rows.first() {
+            Some(first) => first.len(),
+            None => return Err(MatrixError::EmptyMatrix),
+        };
+        if let Some(ragged) = rows.iter().find(|row| row.len() != cols) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (1, cols),
+                actual: (1, ragged.len()),
+            });
+        }
+
+        let row_count = rows.len();
+        Self::from_vec(row_count, cols, rows.into_iter().flatten().collect())
+    }
+
+    // Interprets `data` column by column (Fortran order)
+    pub fn from_vec_col_major(rows: usize, cols: usize, data: Vec<T>) -> MatrixResult<Matrix<T>> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidDimensions);
+        }
+        if data.len() != rows * cols {
+            // `actual` counts the complete columns that `data` holds
+            return Err(MatrixError::DimensionMismatch {
+                expected: (rows, cols),
+                actual: (rows, data.len() / rows),
+            });
+        }
+        let mut mat = Vec::with_capacity(rows * cols);
+        for r in 0..rows {
+            mat.extend((0..cols).map(|c| data[c * rows + r]));
+        }
+        Ok(Self {
+            rows,
+            cols,
+            mat,
+            concurrent: true,
+        })
+    }
+
     pub fn identity(size: usize) -> MatrixResult<Matrix<T>>
     where
         T: From<i32>,
@@ -104,6 +156,27 @@ where
         })
     }
 
+    pub fn fill(rows: usize, cols: usize, value: T) -> MatrixResult<Matrix<T>> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::InvalidDimensions);
+        }
+        Ok(Self {
+            rows,
+            cols,
+            mat: vec![value; rows * cols],
+            concurrent: true,
+        })
+    }
+
+    // Overwrites every element with `value`, keeping the shape
+    pub fn fill_mut(&mut self, value: T) {
+        if self.concurrent {
+            self.mat.par_iter_mut().for_each(|x| *x = value);
+        } else {
+            self.mat.fill(value);
+        }
+    }
+
     pub fn set_concurrent(&mut self, concurrent: bool) {
         self.concurrent = concurrent;
     }
@@ -161,6 +234,44 @@ where
         Ok(())
     }
 
+    // The row-major backing buffer
+    pub fn as_slice(&self) -> &[T] {
+        &self.mat
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.mat
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.mat
+    }
+
+    // Direct access to the row-major buffer, checked against rows * cols
+    pub fn get_flat(&self, idx: usize) -> MatrixResult<&T> {
+        self.check_flat_bounds(idx)?;
+        Ok(&self.mat[idx])
+    }
+
+    pub fn set_flat(&mut self, idx: usize, value: T) -> MatrixResult<()> {
+        self.check_flat_bounds(idx)?;
+        self.mat[idx] = value;
+        Ok(())
+    }
+
+    fn check_flat_bounds(&self, idx: usize) -> MatrixResult<()> {
+        if idx >= self.mat.len() {
+            Err(MatrixError::IndexOutOfBounds {
+                row: idx / self.cols,
+                col: idx % self.cols,
+                max_row: self.rows,
+                max_col: self.cols,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn row(&self, row: usize) -> MatrixResult<Vec<T>> {
         if row >= self.rows {
             return Err(MatrixError::InvalidRowDimension);
@@ -186,6 +297,24 @@ where
         }
     }
 
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        self.mat.chunks(self.cols)
+    }
+
+    pub fn cols_iter(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        (0..self.cols).map(move |c| (0..self.rows).map(|r| self.mat[r * self.cols + c]).collect())
+    }
+
+    pub fn to_col_major(&self) -> Vec<T> {
+        (0..self.cols)
+            .flat_map(|c| (0..self.rows).map(move |r| self.mat[r * self.cols + c]))
+            .collect()
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.rows_iter().map(|row| row.to_vec()).collect()
+    }
+
     pub fn transpose(&self) -> MatrixResult<Matrix<T>> {
         let mut result = Matrix::new(self.cols, self.rows)?;
         result.set_concurrent(self.concurrent);
@@ -209,6 +338,168 @@ where
         Ok(result)
     }
 
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> MatrixResult<()> {
+        if a >= self.cols || b >= self.cols {
+            return Err(MatrixError::InvalidColumnDimension);
+        }
+        if a != b {
+            for row in self.mat.chunks_mut(self.cols) {
+                row.swap(a, b);
+            }
+        }
+        Ok(())
+    }
+
+    // Transposes a square matrix without allocating a second buffer
+    pub fn transpose_in_place(&mut self) -> MatrixResult<()> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let n = self.rows;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                self.mat.swap(i * n + j, j * n + i);
+            }
+        }
+        Ok(())
+    }
+
+    // Takes self where the mask is true and other where it is false
+    pub fn select(&self, mask: &Matrix<bool>, other: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        for dims in [mask.dimensions(), other.dimensions()] {
+            if dims != self.dimensions() {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: self.dimensions(),
+                    actual: dims,
+                });
+            }
+        }
+
+        let mut result = Matrix::new(self.rows, self.cols)?;
+        result.set_concurrent(self.concurrent || mask.concurrent || other.concurrent);
+
+        if result.concurrent {
+            result.mat.par_iter_mut()
+                .enumerate()
+                .for_each(|(i, val)| {
+                    *val = if mask.mat[i] { self.mat[i] } else { other.mat[i] };
+                });
+        } else {
+            for i in 0..self.mat.len() {
+                result.mat[i] = if mask.mat[i] { self.mat[i] } else { other.mat[i] };
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Column-wise concatenation [self | other]
+    pub fn augment(&self, other: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        if self.rows != other.rows {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "augment".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (other.rows, other.cols),
+            });
+        }
+
+        let cols = self.cols + other.cols;
+        let mut result = Matrix::new(self.rows, cols)?;
+        result.set_concurrent(self.concurrent || other.concurrent);
+
+        for (i, row) in result.mat.chunks_mut(cols).enumerate() {
+            row[..self.cols].copy_from_slice(&self.mat[i * self.cols..(i + 1) * self.cols]);
+            row[self.cols..].copy_from_slice(&other.mat[i * other.cols..(i + 1) * other.cols]);
+        }
+
+        Ok(result)
+    }
+
+    // Splits at the midpoints into (top-left, top-right, bottom-left, bottom-right);
+    // with odd dimensions the top and left blocks get the extra row/column
+    pub fn split_quadrants(&self) -> MatrixResult<Quadrants<T>> {
+        if self.rows < 2 || self.cols < 2 {
+            return Err(MatrixError::InvalidDimensions);
+        }
+
+        let (top, left) = (self.rows.div_ceil(2), self.cols.div_ceil(2));
+        let (bottom, right) = (self.rows - top, self.cols - left);
+        Ok((
+            self.block(0, 0, top, left),
+            self.block(0, left, top, right),
+            self.block(top, 0, bottom, left),
+            self.block(top, left, bottom, right),
+        ))
+    }
+
+    pub fn from_quadrants(
+        top_left: &Matrix<T>,
+        top_right: &Matrix<T>,
+        bottom_left: &Matrix<T>,
+        bottom_right: &Matrix<T>,
+    ) -> MatrixResult<Matrix<T>> {
+        let pairs = [
+            (top_left, top_right, top_left.rows == top_right.rows),
+            (bottom_left, bottom_right, bottom_left.rows == bottom_right.rows),
+            (top_left, bottom_left, top_left.cols == bottom_left.cols),
+            (top_right, bottom_right, top_right.cols == bottom_right.cols),
+        ];
+        if let Some((a, b, _)) = pairs.iter().find(|(_, _, fits)| !fits) {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "from_quadrants".to_string(),
+                dim1: (a.rows, a.cols),
+                dim2: (b.rows, b.cols),
+            });
+        }
+
+        let top = top_left.augment(top_right)?;
+        let bottom = bottom_left.augment(bottom_right)?;
+        let mut mat = top.mat;
+        mat.extend_from_slice(&bottom.mat);
+        Ok(Self {
+            rows: top.rows + bottom.rows,
+            cols: top.cols,
+            mat,
+            concurrent: top.concurrent || bottom.concurrent,
+        })
+    }
+
+    fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> Matrix<T> {
+        let mut mat = Vec::with_capacity(rows * cols);
+        for i in row..row + rows {
+            mat.extend_from_slice(&self.mat[i * self.cols + col..i * self.cols + col + cols]);
+        }
+        Self {
+            rows,
+            cols,
+            mat,
+            concurrent: self.concurrent,
+        }
+    }
+
+    // Keeps the overlapping top-left region; new cells get `fill`, extra ones are dropped
+    pub fn resize(&self, new_rows: usize, new_cols: usize, fill: T) -> MatrixResult<Matrix<T>> {
+        if new_rows == 0 || new_cols == 0 {
+            return Err(MatrixError::InvalidDimensions);
+        }
+
+        let mut mat = vec![fill; new_rows * new_cols];
+        let keep_cols = self.cols.min(new_cols);
+        for (i, row) in mat.chunks_mut(new_cols).take(self.rows).enumerate() {
+            row[..keep_cols].copy_from_slice(&self.mat[i * self.cols..i * self.cols + keep_cols]);
+        }
+
+        Ok(Self {
+            rows: new_rows,
+            cols: new_cols,
+            mat,
+            concurrent: self.concurrent,
+        })
+    }
+
     pub fn trace(&self) -> MatrixResult<T>
     where
         T: std::ops::Add<Output = T>,
@@ -233,6 +524,19 @@ where
             Ok(sum)
         }
     }
+
+    /// Folds every element into `identity` with `f`.
+    /// In concurrent mode the elements are combined in an unspecified order,
+    /// so `f` must be associative and `identity` must be its neutral element.
+    pub fn reduce<F: Fn(T, T) -> T + Sync>(&self, identity: T, f: F) -> T {
+        if self.concurrent {
+            self.mat.par_iter()
+                .copied()
+                .reduce(|| identity, &f)
+        } else {
+            self.mat.iter().fold(identity, |acc, &x| f(acc, x))
+        }
+    }
 }
 
 // Arithmetic operations for numeric types
@@ -240,6 +544,82 @@ impl<T> Matrix<T>
 where
     T: Default + Copy + Clone + Send + Sync + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T> + PartialEq,
 {
+    pub fn row_sums(&self) -> Vec<T> {
+        let sum_row = |row: &[T]| row.iter().fold(T::default(), |acc, &x| acc + x);
+        if self.concurrent {
+            self.mat.par_chunks(self.cols).map(sum_row).collect()
+        } else {
+            self.mat.chunks(self.cols).map(sum_row).collect()
+        }
+    }
+
+    pub fn col_sums(&self) -> Vec<T> {
+        // Same striding as `col`: element (r, c) lives at r * cols + c
+        let sum_col = |c: usize| (0..self.rows).fold(T::default(), |acc, r| acc + self.mat[r * self.cols + c]);
+        if self.concurrent {
+            (0..self.cols).into_par_iter().map(sum_col).collect()
+        } else {
+            (0..self.cols).map(sum_col).collect()
+        }
+    }
+
+    // sum(w_i * a_ii)
+    pub fn weighted_trace(&self, weights: &[T]) -> MatrixResult<T> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        if weights.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, 1),
+                actual: (weights.len(), 1),
+            });
+        }
+
+        if self.concurrent {
+            Ok((0..self.rows)
+                .into_par_iter()
+                .map(|i| weights[i] * self.mat[i * self.cols + i])
+                .reduce(|| T::default(), |a, b| a + b))
+        } else {
+            let mut sum = T::default();
+            for (i, &weight) in weights.iter().enumerate() {
+                sum = sum + weight * self.mat[i * self.cols + i];
+            }
+            Ok(sum)
+        }
+    }
+
+    // u * v^T for column vectors u (m x 1) and v (n x 1), giving an m x n matrix
+    pub fn outer_product(&self, other: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        if self.cols != 1 || other.cols != 1 {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "outer product".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (other.rows, other.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, other.rows)?;
+        result.set_concurrent(self.concurrent || other.concurrent);
+
+        let fill_row = |(row, &u): (&mut [T], &T)| {
+            for (val, &v) in row.iter_mut().zip(&other.mat) {
+                *val = u * v;
+            }
+        };
+        if result.concurrent {
+            result.mat.par_chunks_mut(other.rows).zip(self.mat.par_iter()).for_each(fill_row);
+        } else {
+            result.mat.chunks_mut(other.rows).zip(self.mat.iter()).for_each(fill_row);
+        }
+
+        Ok(result)
+    }
+
     pub fn dot_product(&self, other: &Matrix<T>) -> MatrixResult<Matrix<T>> {
         if self.rows != other.rows || self.cols != other.cols {
             return Err(MatrixError::IncompatibleDimensions {
@@ -267,6 +647,28 @@ where
         Ok(result)
     }
 
+    // Raises every element to `exponent` by repeated multiplication (element-wise, not a matrix power).
+    // An exponent of 0 gives a matrix of ones.
+    pub fn hadamard_pow(&self, exponent: u32) -> Matrix<T>
+    where
+        T: One,
+    {
+        let pow = |x: T| (0..exponent).fold(T::one(), |acc, _| acc * x);
+
+        let mat = if self.concurrent {
+            self.mat.par_iter().map(|&x| pow(x)).collect()
+        } else {
+            self.mat.iter().map(|&x| pow(x)).collect()
+        };
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            mat,
+            concurrent: self.concurrent,
+        }
+    }
+
     pub fn matrix_multiply(&self, other: &Matrix<T>) -> MatrixResult<Matrix<T>> {
         if self.cols != other.rows {
             return Err(MatrixError::IncompatibleDimensions {
@@ -305,57 +707,255 @@ where
 
         Ok(result)
     }
-}
 
-// Determinant and matrix operations for floating point types
-impl<T> Matrix<T>
-where
-    T: Default + Copy + Clone + Send + Sync + std::ops::Add<Output = T> + std::ops::Sub<Output = T> 
-        + std::ops::Mul<Output = T> + std::ops::Div<Output = T> + std::ops::Neg<Output = T> 
-        + PartialEq + PartialOrd + From<i32>,
-{
-    pub fn determinant(&self) -> MatrixResult<T> {
-        if !self.is_square() {
-            return Err(MatrixError::NotSquareMatrix {
-                rows: self.rows,
-                cols: self.cols,
+    // BLAS-style alpha * (self * b) + beta * c, written straight into one output buffer
+    pub fn gemm(&self, b: &Matrix<T>, c: &Matrix<T>, alpha: T, beta: T) -> MatrixResult<Matrix<T>> {
+        if self.cols != b.rows {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "matrix multiplication".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (b.rows, b.cols),
             });
         }
-
-        if self.rows == 1 {
-            return Ok(self.mat[0]);
+        if c.rows != self.rows || c.cols != b.cols {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "gemm accumulation".to_string(),
+                dim1: (self.rows, b.cols),
+                dim2: (c.rows, c.cols),
+            });
         }
 
-        if self.rows == 2 {
-            return Ok(self.mat[0] * self.mat[3] - self.mat[1] * self.mat[2]);
+        let mut result = Matrix::new(self.rows, b.cols)?;
+        result.set_concurrent(self.concurrent || b.concurrent || c.concurrent);
+
+        let fill_row = |(i, row): (usize, &mut [T])| {
+            for (j, val) in row.iter_mut().enumerate() {
+                let mut sum = T::default();
+                for k in 0..self.cols {
+                    sum = sum + self.mat[i * self.cols + k] * b.mat[k * b.cols + j];
+                }
+                *val = alpha * sum + beta * c.mat[i * b.cols + j];
+            }
+        };
+
+        if result.concurrent {
+            result.mat.par_chunks_mut(b.cols).enumerate().for_each(fill_row);
+        } else {
+            result.mat.chunks_mut(b.cols).enumerate().for_each(fill_row);
         }
 
-        // For larger matrices, use LU decomposition or cofactor expansion
-        self.determinant_lu()
+        Ok(result)
     }
 
-    fn determinant_lu(&self) -> MatrixResult<T> {
-        let mut matrix = self.clone();
-        let mut det = T::from(1);
-        let n = self.rows;
+    // Valid 2D cross-correlation (the kernel is not flipped), one output row per kernel position
+    pub fn convolve2d(&self, kernel: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        if kernel.rows > self.rows || kernel.cols > self.cols {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "2D convolution".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (kernel.rows, kernel.cols),
+            });
+        }
 
-        for i in 0..n {
-            // Find pivot
-            let mut max_row = i;
-            for k in (i + 1)..n {
-                if matrix.mat[k * n + i] > matrix.mat[max_row * n + i] 
-                    || matrix.mat[k * n + i] < T::default() - matrix.mat[max_row * n + i] {
-                    max_row = k;
+        let out_rows = self.rows - kernel.rows + 1;
+        let out_cols = self.cols - kernel.cols + 1;
+        let mut result = Matrix::new(out_rows, out_cols)?;
+        result.set_concurrent(self.concurrent || kernel.concurrent);
+
+        let fill_row = |(i, row): (usize, &mut [T])| {
+            for (j, val) in row.iter_mut().enumerate() {
+                let mut sum = T::default();
+                for ki in 0..kernel.rows {
+                    for kj in 0..kernel.cols {
+                        sum = sum + self.mat[(i + ki) * self.cols + j + kj] * kernel.mat[ki * kernel.cols + kj];
+                    }
                 }
+                *val = sum;
             }
+        };
 
-            // Swap rows if needed
-            if max_row != i {
-                for j in 0..n {
-                    let temp = matrix.mat[i * n + j];
-                    matrix.mat[i * n + j] = matrix.mat[max_row * n + j];
-                    matrix.mat[max_row * n + j] = temp;
-                }
+        if result.concurrent {
+            result.mat.par_chunks_mut(out_cols).enumerate().for_each(fill_row);
+        } else {
+            result.mat.chunks_mut(out_cols).enumerate().for_each(fill_row);
+        }
+
+        Ok(result)
+    }
+
+    pub fn add_bias_broadcast(&self, bias: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        if bias.rows != self.rows || bias.cols != 1 {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "bias broadcast".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (bias.rows, bias.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, self.cols)?;
+        result.set_concurrent(self.concurrent || bias.concurrent);
+
+        if result.concurrent {
+            result.mat.par_chunks_mut(self.cols)
+                .enumerate()
+                .for_each(|(i, row)| {
+                    for (j, val) in row.iter_mut().enumerate() {
+                        *val = self.mat[i * self.cols + j] + bias.mat[i];
+                    }
+                });
+        } else {
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    result.mat[i * self.cols + j] = self.mat[i * self.cols + j] + bias.mat[i];
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Subtracts v[i] from every element of row i, i.e. an n x 1 vector from each column
+    pub fn sub_col_vector(&self, v: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        if v.rows != self.rows || v.cols != 1 {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "column vector subtraction".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (v.rows, v.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, self.cols)?;
+        result.set_concurrent(self.concurrent || v.concurrent);
+
+        if result.concurrent {
+            result.mat.par_chunks_mut(self.cols)
+                .enumerate()
+                .for_each(|(i, row)| {
+                    for (j, val) in row.iter_mut().enumerate() {
+                        *val = self.mat[i * self.cols + j] - v.mat[i];
+                    }
+                });
+        } else {
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    result.mat[i * self.cols + j] = self.mat[i * self.cols + j] - v.mat[i];
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Subtracts v[j] from every element of column j, e.g. per-sample maxima before a softmax
+    pub fn sub_row_vector(&self, v: &Matrix<T>) -> MatrixResult<Matrix<T>> {
+        if v.rows != 1 || v.cols != self.cols {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "row vector subtraction".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (v.rows, v.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, self.cols)?;
+        result.set_concurrent(self.concurrent || v.concurrent);
+
+        if result.concurrent {
+            result.mat.par_chunks_mut(self.cols)
+                .enumerate()
+                .for_each(|(i, row)| {
+                    for (j, val) in row.iter_mut().enumerate() {
+                        *val = self.mat[i * self.cols + j] - v.mat[j];
+                    }
+                });
+        } else {
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    result.mat[i * self.cols + j] = self.mat[i * self.cols + j] - v.mat[j];
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn add_assign_broadcast(&mut self, bias: &Matrix<T>) -> MatrixResult<()> {
+        if bias.rows != self.rows || bias.cols != 1 {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "bias broadcast".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: (bias.rows, bias.cols),
+            });
+        }
+
+        let cols = self.cols;
+        if self.concurrent {
+            self.mat.par_chunks_mut(cols)
+                .enumerate()
+                .for_each(|(i, row)| {
+                    for val in row.iter_mut() {
+                        *val = *val + bias.mat[i];
+                    }
+                });
+        } else {
+            for i in 0..self.rows {
+                for j in 0..cols {
+                    self.mat[i * cols + j] = self.mat[i * cols + j] + bias.mat[i];
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Determinant and matrix operations for floating point types. The bound is `One` rather than
+// `From<i32>`, which f32 does not implement (the conversion is lossy)
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync + std::ops::Add<Output = T> + std::ops::Sub<Output = T> 
+        + std::ops::Mul<Output = T> + std::ops::Div<Output = T> + std::ops::Neg<Output = T> 
+        + PartialEq + PartialOrd + One,
+{
+    pub fn determinant(&self) -> MatrixResult<T> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        if self.rows == 1 {
+            return Ok(self.mat[0]);
+        }
+
+        if self.rows == 2 {
+            return Ok(self.mat[0] * self.mat[3] - self.mat[1] * self.mat[2]);
+        }
+
+        // For larger matrices, use LU decomposition or cofactor expansion
+        self.determinant_lu()
+    }
+
+    fn determinant_lu(&self) -> MatrixResult<T> {
+        let mut matrix = self.clone();
+        let mut det = T::one();
+        let n = self.rows;
+
+        for i in 0..n {
+            // Find pivot
+            let mut max_row = i;
+            for k in (i + 1)..n {
+                if matrix.mat[k * n + i] > matrix.mat[max_row * n + i] 
+                    || matrix.mat[k * n + i] < T::default() - matrix.mat[max_row * n + i] {
+                    max_row = k;
+                }
+            }
+
+            // Swap rows if needed
+            if max_row != i {
+                for j in 0..n {
+                    matrix.mat.swap(i * n + j, max_row * n + j);
+                }
                 det = T::default() - det;
             }
 
@@ -366,12 +966,21 @@ where
 
             det = det * matrix.mat[i * n + i];
 
-            // Eliminate below diagonal
-            for k in (i + 1)..n {
-                let factor = matrix.mat[k * n + i] / matrix.mat[i * n + i];
+            // Eliminate below diagonal. Only the pivot search and row swap depend on earlier steps;
+            // once the pivot row is fixed, every row below it is updated independently.
+            let (upper, lower) = matrix.mat.split_at_mut((i + 1) * n);
+            let pivot_row = &upper[i * n..];
+            let eliminate = |row: &mut [T]| {
+                let factor = row[i] / pivot_row[i];
                 for j in i..n {
-                    matrix.mat[k * n + j] = matrix.mat[k * n + j] - factor * matrix.mat[i * n + j];
+                    row[j] = row[j] - factor * pivot_row[j];
                 }
+            };
+
+            if self.concurrent && n >= PARALLEL_DETERMINANT_SIZE {
+                lower.par_chunks_mut(n).for_each(eliminate);
+            } else {
+                lower.chunks_mut(n).for_each(eliminate);
             }
         }
 
@@ -392,13 +1001,14 @@ where
         if self.concurrent {
             result.mat.par_chunks_mut(self.cols)
                 .enumerate()
-                .for_each(|(i, row)| {
+                .try_for_each(|(i, row)| -> MatrixResult<()> {
                     for (j, val) in row.iter_mut().enumerate() {
-                        let minor = self.minor_matrix(i, j).unwrap();
-                        let cofactor = minor.determinant().unwrap();
+                        let minor = self.minor_matrix(i, j)?;
+                        let cofactor = minor.determinant()?;
                         *val = if (i + j) % 2 == 0 { cofactor } else { T::default() - cofactor };
                     }
-                });
+                    Ok(())
+                })?;
         } else {
             for i in 0..self.rows {
                 for j in 0..self.cols {
@@ -418,6 +1028,7 @@ where
         }
 
         let mut result = Matrix::new(self.rows - 1, self.cols - 1)?;
+        result.set_concurrent(self.concurrent);
         let mut result_row = 0;
 
         for i in 0..self.rows {
@@ -437,6 +1048,497 @@ where
 
         Ok(result)
     }
+
+    // Element-wise comparisons against a scalar, producing a boolean mask
+    pub fn gt_scalar(&self, scalar: T) -> Matrix<bool> {
+        self.mask(|x| x > scalar)
+    }
+
+    pub fn lt_scalar(&self, scalar: T) -> Matrix<bool> {
+        self.mask(|x| x < scalar)
+    }
+
+    pub fn ge_scalar(&self, scalar: T) -> Matrix<bool> {
+        self.mask(|x| x >= scalar)
+    }
+
+    pub fn le_scalar(&self, scalar: T) -> Matrix<bool> {
+        self.mask(|x| x <= scalar)
+    }
+
+    pub fn eq_scalar(&self, scalar: T) -> Matrix<bool> {
+        self.mask(|x| x == scalar)
+    }
+
+    fn mask<F: Fn(T) -> bool + Sync>(&self, predicate: F) -> Matrix<bool> {
+        let mat = if self.concurrent {
+            self.mat.par_iter().map(|&x| predicate(x)).collect()
+        } else {
+            self.mat.iter().map(|&x| predicate(x)).collect()
+        };
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            mat,
+            concurrent: self.concurrent,
+        }
+    }
+
+    pub fn clamp(&self, min: T, max: T) -> Matrix<T> {
+        let clamp_value = |x: T| if x < min { min } else if x > max { max } else { x };
+        let mut result = self.clone();
+
+        if self.concurrent {
+            result.mat.par_iter_mut().for_each(|val| *val = clamp_value(*val));
+        } else {
+            for val in result.mat.iter_mut() {
+                *val = clamp_value(*val);
+            }
+        }
+
+        result
+    }
+}
+
+// Decompositions and solvers that need Float (sqrt, abs, epsilon)
+impl<T> Matrix<T>
+where
+    T: Float + Default + Send + Sync,
+{
+    // L2 norm of every row / column
+    pub fn row_norms(&self) -> Vec<T> {
+        let norm = |row: &[T]| row.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+        if self.concurrent {
+            self.mat.par_chunks(self.cols).map(norm).collect()
+        } else {
+            self.mat.chunks(self.cols).map(norm).collect()
+        }
+    }
+
+    pub fn col_norms(&self) -> Vec<T> {
+        let mut sums = vec![T::zero(); self.cols];
+        for row in self.mat.chunks(self.cols) {
+            for (sum, &x) in sums.iter_mut().zip(row) {
+                *sum = *sum + x * x;
+            }
+        }
+        sums.into_iter().map(T::sqrt).collect()
+    }
+
+    // Scales every row (column) to unit L2 norm; all-zero rows (columns) are left as they are
+    pub fn normalize_rows(&self) -> MatrixResult<Matrix<T>> {
+        let norms = self.row_norms();
+        let mut result = self.clone();
+        let scale_row = |(row, &norm): (&mut [T], &T)| {
+            if norm > T::zero() {
+                row.iter_mut().for_each(|x| *x = *x / norm);
+            }
+        };
+        if self.concurrent {
+            result.mat.par_chunks_mut(self.cols).zip(norms.par_iter()).for_each(scale_row);
+        } else {
+            result.mat.chunks_mut(self.cols).zip(norms.iter()).for_each(scale_row);
+        }
+        Ok(result)
+    }
+
+    pub fn normalize_cols(&self) -> MatrixResult<Matrix<T>> {
+        let norms = self.col_norms();
+        let mut result = self.clone();
+        let scale_row = |row: &mut [T]| {
+            for (x, &norm) in row.iter_mut().zip(&norms) {
+                if norm > T::zero() {
+                    *x = *x / norm;
+                }
+            }
+        };
+        if self.concurrent {
+            result.mat.par_chunks_mut(self.cols).for_each(scale_row);
+        } else {
+            result.mat.chunks_mut(self.cols).for_each(scale_row);
+        }
+        Ok(result)
+    }
+
+    // Population statistics over all elements (a matrix is never empty, so these are always defined)
+    pub fn mean(&self) -> T {
+        Self::lane_mean(&self.mat, self.concurrent)
+    }
+
+    pub fn variance(&self) -> T {
+        Self::lane_variance(&self.mat, self.concurrent)
+    }
+
+    pub fn std_dev(&self) -> T {
+        self.variance().sqrt()
+    }
+
+    // Population variance of every column (axis 0) or every row (axis 1)
+    pub fn variance_axis(&self, axis: usize) -> MatrixResult<Vec<T>> {
+        match axis {
+            0 => Ok(self.cols_iter().map(|col| Self::lane_variance(&col, false)).collect()),
+            1 if self.concurrent => Ok(self.mat.par_chunks(self.cols).map(|row| Self::lane_variance(row, false)).collect()),
+            1 => Ok(self.mat.chunks(self.cols).map(|row| Self::lane_variance(row, false)).collect()),
+            _ => Err(MatrixError::InvalidOperation(format!(
+                "axis {} out of range for a matrix, expected 0 (columns) or 1 (rows)", axis
+            ))),
+        }
+    }
+
+    pub fn std_dev_axis(&self, axis: usize) -> MatrixResult<Vec<T>> {
+        Ok(self.variance_axis(axis)?.into_iter().map(T::sqrt).collect())
+    }
+
+    fn lane_mean(values: &[T], parallel: bool) -> T {
+        let sum = if parallel {
+            values.par_iter().copied().reduce(T::zero, |a, b| a + b)
+        } else {
+            values.iter().fold(T::zero(), |acc, &x| acc + x)
+        };
+        sum / Self::element_count(values.len())
+    }
+
+    fn lane_variance(values: &[T], parallel: bool) -> T {
+        let mean = Self::lane_mean(values, parallel);
+        let squared_deviation = |&x: &T| (x - mean) * (x - mean);
+        let sum = if parallel {
+            values.par_iter().map(squared_deviation).reduce(T::zero, |a, b| a + b)
+        } else {
+            values.iter().map(squared_deviation).fold(T::zero(), |acc, x| acc + x)
+        };
+        sum / Self::element_count(values.len())
+    }
+
+    // Every usize is representable (if rounded) as a float, so the cast cannot fail
+    fn element_count(n: usize) -> T {
+        T::from(n).expect("usize converts to a float")
+    }
+
+    pub fn qr_decomposition(&self) -> MatrixResult<(Matrix<T>, Matrix<T>)> {
+        if self.rows < self.cols {
+            return Err(MatrixError::InvalidOperation(format!(
+                "QR decomposition requires rows >= cols, got {}x{}",
+                self.rows, self.cols
+            )));
+        }
+
+        let (m, n) = (self.rows, self.cols);
+        let mut columns = (0..n).map(|j| self.col(j)).collect::<MatrixResult<Vec<Vec<T>>>>()?;
+        let mut r = Matrix::zeros(n, n)?;
+        r.set_concurrent(self.concurrent);
+
+        // A column left with only rounding noise after projection is linearly dependent
+        let max_abs = self.mat.iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let tol = T::epsilon() * Self::element_count(m) * max_abs;
+
+        // Modified Gram-Schmidt: orthogonalize the remaining columns against each new q_k
+        for k in 0..n {
+            let norm = columns[k].iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            if norm <= tol {
+                return Err(MatrixError::SingularMatrix);
+            }
+            r.mat[k * n + k] = norm;
+            columns[k].iter_mut().for_each(|x| *x = *x / norm);
+
+            let (done, rest) = columns.split_at_mut(k + 1);
+            let q_k = &done[k];
+            for (offset, column) in rest.iter_mut().enumerate() {
+                let projection = q_k.iter().zip(column.iter()).fold(T::zero(), |acc, (&q, &v)| acc + q * v);
+                r.mat[k * n + k + 1 + offset] = projection;
+                column.iter_mut().zip(q_k).for_each(|(v, &q)| *v = *v - projection * q);
+            }
+        }
+
+        let mut q = Matrix::zeros(m, n)?;
+        q.set_concurrent(self.concurrent);
+        for (j, column) in columns.iter().enumerate() {
+            for (i, &value) in column.iter().enumerate() {
+                q.mat[i * n + j] = value;
+            }
+        }
+
+        Ok((q, r))
+    }
+
+    // Determinant via LU with partial pivoting. Unlike `determinant`, which returns zero, a pivot
+    // within rounding noise of zero is reported as SingularMatrix; inverses and solvers should use this
+    pub fn det_checked(&self) -> MatrixResult<T> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let mut m = self.mat.clone();
+        let max_abs = m.iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let scale = T::from(n).ok_or(MatrixError::InvalidDimensions)?;
+        let tol = T::epsilon() * scale * max_abs;
+        let mut det = T::one();
+
+        for i in 0..n {
+            let mut max_row = i;
+            for k in (i + 1)..n {
+                if m[k * n + i].abs() > m[max_row * n + i].abs() {
+                    max_row = k;
+                }
+            }
+            if m[max_row * n + i].abs() <= tol {
+                return Err(MatrixError::SingularMatrix);
+            }
+
+            if max_row != i {
+                for j in 0..n {
+                    m.swap(i * n + j, max_row * n + j);
+                }
+                det = -det;
+            }
+
+            let pivot = m[i * n + i];
+            det = det * pivot;
+            for k in (i + 1)..n {
+                let factor = m[k * n + i] / pivot;
+                for j in i..n {
+                    m[k * n + j] = m[k * n + j] - factor * m[i * n + j];
+                }
+            }
+        }
+
+        Ok(det)
+    }
+
+    // Lower-triangular L with self = L * L^T. The matrix must be symmetric (up to rounding) and
+    // every pivot positive; anything else is NotPositiveDefinite
+    pub fn cholesky(&self) -> MatrixResult<Matrix<T>> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let max_abs = self.mat.iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let scale = T::from(n).ok_or(MatrixError::InvalidDimensions)?;
+        let tol = T::epsilon() * scale * max_abs;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if (self.mat[i * n + j] - self.mat[j * n + i]).abs() > tol {
+                    return Err(MatrixError::NotPositiveDefinite);
+                }
+            }
+        }
+
+        let mut l = Matrix::new(n, n)?;
+        l.set_concurrent(self.concurrent);
+        for j in 0..n {
+            let diagonal = (0..j).fold(self.mat[j * n + j], |acc, k| acc - l.mat[j * n + k] * l.mat[j * n + k]);
+            if diagonal <= T::zero() || diagonal.is_nan() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            let pivot = diagonal.sqrt();
+            l.mat[j * n + j] = pivot;
+
+            for i in (j + 1)..n {
+                let sum = (0..j).fold(self.mat[i * n + j], |acc, k| acc - l.mat[i * n + k] * l.mat[j * n + k]);
+                l.mat[i * n + j] = sum / pivot;
+            }
+        }
+
+        Ok(l)
+    }
+
+    // Property checks for validating decompositions
+    pub fn is_positive_definite(&self) -> bool {
+        self.cholesky().is_ok()
+    }
+
+    // Whether self^T * self is the identity to within `tol` in every element
+    pub fn is_orthogonal(&self, tol: f64) -> bool {
+        let Some(tol) = T::from(tol) else { return false };
+        if !self.is_square() {
+            return false;
+        }
+
+        let n = self.rows;
+        (0..n).all(|i| {
+            (0..n).all(|j| {
+                let product = (0..n).fold(T::zero(), |acc, k| acc + self.mat[k * n + i] * self.mat[k * n + j]);
+                let expected = if i == j { T::one() } else { T::zero() };
+                (product - expected).abs() <= tol
+            })
+        })
+    }
+
+    // Gauss-Jordan elimination with partial pivoting; values within rounding noise count as zero
+    pub fn rref(&self) -> MatrixResult<Matrix<T>> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut m = self.clone();
+
+        let max_abs = m.mat.iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let scale = T::from(rows.max(cols)).ok_or(MatrixError::InvalidDimensions)?;
+        let tol = T::epsilon() * scale * max_abs;
+
+        let mut pivot_row = 0;
+        for col in 0..cols {
+            if pivot_row == rows {
+                break;
+            }
+
+            // Find pivot
+            let mut max_row = pivot_row;
+            for k in (pivot_row + 1)..rows {
+                if m.mat[k * cols + col].abs() > m.mat[max_row * cols + col].abs() {
+                    max_row = k;
+                }
+            }
+            if m.mat[max_row * cols + col].abs() <= tol {
+                continue;
+            }
+
+            if max_row != pivot_row {
+                for j in 0..cols {
+                    m.mat.swap(pivot_row * cols + j, max_row * cols + j);
+                }
+            }
+
+            let pivot = m.mat[pivot_row * cols + col];
+            for j in 0..cols {
+                m.mat[pivot_row * cols + j] = m.mat[pivot_row * cols + j] / pivot;
+            }
+
+            // Eliminate the column everywhere else
+            for k in 0..rows {
+                let factor = m.mat[k * cols + col];
+                if k == pivot_row || factor == T::zero() {
+                    continue;
+                }
+                for j in 0..cols {
+                    m.mat[k * cols + j] = m.mat[k * cols + j] - factor * m.mat[pivot_row * cols + j];
+                }
+            }
+
+            pivot_row += 1;
+        }
+
+        m.mat.iter_mut().filter(|x| x.abs() <= tol).for_each(|x| *x = T::zero());
+        Ok(m)
+    }
+
+    pub fn power_iteration(&self, max_iters: usize, tol: f64) -> MatrixResult<(T, Matrix<T>)> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let tol = T::from(tol).ok_or_else(|| {
+            MatrixError::InvalidOperation("tolerance is not representable in the element type".to_string())
+        })?;
+
+        // Deterministic start: the normalized all-ones vector
+        let start = T::one() / T::from(n).ok_or(MatrixError::InvalidDimensions)?.sqrt();
+        let mut v = Matrix::from_vec(n, 1, vec![start; n])?;
+        v.set_concurrent(self.concurrent);
+        let mut previous: Option<T> = None;
+
+        for _ in 0..max_iters {
+            let w = self.matrix_multiply(&v)?;
+            let norm = w.mat.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            if norm == T::zero() {
+                return Ok((T::zero(), v));
+            }
+            v = w;
+            v.mat.iter_mut().for_each(|x| *x = *x / norm);
+
+            // Rayleigh quotient v^T * A * v for the unit vector v
+            let av = self.matrix_multiply(&v)?;
+            let estimate = v.mat.iter().zip(av.mat.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b);
+            // Convergence needs two estimates to compare, not one estimate and a placeholder zero
+            if previous.is_some_and(|prev| (estimate - prev).abs() < tol) {
+                return Ok((estimate, v));
+            }
+            previous = Some(estimate);
+        }
+
+        Err(MatrixError::InvalidOperation(format!(
+            "power iteration did not converge within {} iterations",
+            max_iters
+        )))
+    }
+}
+
+// Exact operations for primitive integer types
+impl<T> Matrix<T>
+where
+    T: PrimInt + Default + Send + Sync,
+{
+    // Bareiss fraction-free elimination: every division is exact, so no precision is lost
+    pub fn determinant_exact(&self) -> MatrixResult<T>
+    where
+        T: Signed,
+    {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquareMatrix {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let mut m = self.mat.clone();
+        let mut sign = T::one();
+        let mut prev_pivot = T::one();
+
+        for k in 0..n - 1 {
+            if m[k * n + k] == T::zero() {
+                match (k + 1..n).find(|&i| m[i * n + k] != T::zero()) {
+                    Some(i) => {
+                        for j in 0..n {
+                            m.swap(k * n + j, i * n + j);
+                        }
+                        sign = -sign;
+                    }
+                    None => return Ok(T::zero()),
+                }
+            }
+
+            let pivot = m[k * n + k];
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    m[i * n + j] = (m[i * n + j] * pivot - m[i * n + k] * m[k * n + j]) / prev_pivot;
+                }
+                m[i * n + k] = T::zero();
+            }
+            prev_pivot = pivot;
+        }
+
+        Ok(sign * m[n * n - 1])
+    }
+
+    pub fn checked_scalar_mul(&self, scalar: T) -> MatrixResult<Matrix<T>> {
+        let mut result = Matrix::new(self.rows, self.cols)?;
+        result.set_concurrent(self.concurrent);
+
+        if self.concurrent {
+            result.mat.par_iter_mut()
+                .enumerate()
+                .try_for_each(|(i, val)| {
+                    *val = self.mat[i].checked_mul(&scalar).ok_or(MatrixError::ArithmeticOverflow { index: i })?;
+                    Ok(())
+                })?;
+        } else {
+            for i in 0..self.mat.len() {
+                result.mat[i] = self.mat[i].checked_mul(&scalar).ok_or(MatrixError::ArithmeticOverflow { index: i })?;
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 // Index traits with error handling
@@ -449,8 +1551,8 @@ where
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         let (row, col) = index;
         if row >= self.rows || col >= self.cols {
-            panic!("Index ({}, {}) out of bounds for matrix of size {}x{}", 
-                   row, col, self.rows, self.cols);
+            panic!("Index ({}, {}) out of bounds for matrix of size {}x{} (flat offset {})",
+                   row, col, self.rows, self.cols, row.wrapping_mul(self.cols).wrapping_add(col));
         }
         &self.mat[row * self.cols + col]
     }
@@ -463,13 +1565,21 @@ where
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         let (row, col) = index;
         if row >= self.rows || col >= self.cols {
-            panic!("Index ({}, {}) out of bounds for matrix of size {}x{}", 
-                   row, col, self.rows, self.cols);
+            panic!("Index ({}, {}) out of bounds for matrix of size {}x{} (flat offset {})",
+                   row, col, self.rows, self.cols, row.wrapping_mul(self.cols).wrapping_add(col));
         }
         &mut self.mat[row * self.cols + col]
     }
 }
 
+// Equality of shape and elements; the concurrent flag is an execution detail and is ignored.
+// Float matrices that went through arithmetic should be compared with a tolerance instead.
+impl<T: PartialEq> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.cols == other.cols && self.mat == other.mat
+    }
+}
+
 // Operator overloading for addition
 impl<T> Add for Matrix<T>
 where
@@ -555,6 +1665,22 @@ where
         self.matrix_multiply(&other)
     }
 }
+// Matrix multiplication by reference, without consuming either operand
+impl<T> Mul<&Matrix<T>> for &Matrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync
+        + std::ops::Add<Output = T>
+        + std::ops::Mul<Output = T>
+        + PartialEq
+        + Sub<Output = T>,
+{
+    type Output = MatrixResult<Matrix<T>>;
+
+    fn mul(self, other: &Matrix<T>) -> Self::Output {
+        self.matrix_multiply(other)
+    }
+}
+
 // Scalar multiplication
 impl<T> Mul<T> for Matrix<T>
 where
@@ -562,6 +1688,18 @@ where
 {
     type Output = MatrixResult<Matrix<T>>;
 
+    fn mul(self, scalar: T) -> Self::Output {
+        &self * scalar
+    }
+}
+
+// Scalar multiplication by reference, without consuming the matrix
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync + std::ops::Mul<Output = T>,
+{
+    type Output = MatrixResult<Matrix<T>>;
+
     fn mul(self, scalar: T) -> Self::Output {
         let mut result = Matrix::new(self.rows, self.cols)?;
         result.set_concurrent(self.concurrent);
@@ -589,6 +1727,18 @@ where
 {
     type Output = MatrixResult<Matrix<T>>;
 
+    fn div(self, scalar: T) -> Self::Output {
+        &self / scalar
+    }
+}
+
+// Scalar division by reference
+impl<T> Div<T> for &Matrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync + std::ops::Div<Output = T> + PartialEq,
+{
+    type Output = MatrixResult<Matrix<T>>;
+
     fn div(self, scalar: T) -> Self::Output {
         if scalar == T::default() {
             return Err(MatrixError::DivisionByZero);
@@ -620,6 +1770,18 @@ where
 {
     type Output = MatrixResult<Matrix<T>>;
 
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+// Negation by reference
+impl<T> Neg for &Matrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync + std::ops::Neg<Output = T>,
+{
+    type Output = MatrixResult<Matrix<T>>;
+
     fn neg(self) -> Self::Output {
         let mut result = Matrix::new(self.rows, self.cols)?;
         result.set_concurrent(self.concurrent);
@@ -0,0 +1,13 @@
+use rayon::ThreadPoolBuilder;
+
+/// Runs `f` inside a dedicated rayon pool with `num_threads` workers.
+///
+/// Every parallel matrix operation started inside the closure runs on this pool
+/// instead of the global one, which bounds how many threads the matrix code uses.
+pub fn with_thread_pool<R: Send>(num_threads: usize, f: impl FnOnce() -> R + Send) -> R {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .expect("failed to build rayon thread pool");
+    pool.install(f)
+}
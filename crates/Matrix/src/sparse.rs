@@ -0,0 +1,119 @@
+use std::ops::{Add, Mul};
+use rayon::prelude::*;
+use crate::matrix::Matrix;
+use crate::error::{MatrixError, MatrixResult};
+
+/// A matrix stored in compressed sparse row (CSR) form.
+///
+/// Only the nonzero entries are kept: `values[k]` sits in column `col_indices[k]`, and the entries
+/// of row `i` are `row_ptrs[i]..row_ptrs[i + 1]`. Pruned weight matrices are the intended use.
+#[derive(Debug, Clone)]
+pub struct SparseMatrix<T> {
+    rows: usize,
+    cols: usize,
+    values: Vec<T>,
+    col_indices: Vec<usize>,
+    row_ptrs: Vec<usize>,
+    concurrent: bool,
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Default + Copy + Clone + Send + Sync + PartialEq,
+{
+    // Keeps every element that differs from `T::default()`; the execution mode is carried over
+    pub fn from_dense(dense: &Matrix<T>) -> SparseMatrix<T> {
+        let (rows, cols) = dense.dimensions();
+        let mut values = Vec::new();
+        let mut col_indices = Vec::new();
+        let mut row_ptrs = Vec::with_capacity(rows + 1);
+        row_ptrs.push(0);
+
+        for row in dense.rows_iter() {
+            for (j, &value) in row.iter().enumerate() {
+                if value != T::default() {
+                    values.push(value);
+                    col_indices.push(j);
+                }
+            }
+            row_ptrs.push(values.len());
+        }
+
+        SparseMatrix {
+            rows,
+            cols,
+            values,
+            col_indices,
+            row_ptrs,
+            concurrent: dense.is_concurrent(),
+        }
+    }
+
+    pub fn to_dense(&self) -> MatrixResult<Matrix<T>> {
+        let mut data = vec![T::default(); self.rows * self.cols];
+        for i in 0..self.rows {
+            for k in self.row_ptrs[i]..self.row_ptrs[i + 1] {
+                data[i * self.cols + self.col_indices[k]] = self.values[k];
+            }
+        }
+
+        let mut dense = Matrix::from_vec(self.rows, self.cols, data)?;
+        dense.set_concurrent(self.concurrent);
+        Ok(dense)
+    }
+
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    // Number of stored (nonzero) entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_concurrent(&self) -> bool {
+        self.concurrent
+    }
+
+    pub fn set_concurrent(&mut self, concurrent: bool) {
+        self.concurrent = concurrent;
+    }
+
+    // self * dense, touching only the stored entries; parallel over output rows when concurrent
+    pub fn sparse_dense_multiply(&self, dense: &Matrix<T>) -> MatrixResult<Matrix<T>>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        if self.cols != dense.rows() {
+            return Err(MatrixError::IncompatibleDimensions {
+                op: "sparse-dense multiplication".to_string(),
+                dim1: (self.rows, self.cols),
+                dim2: dense.dimensions(),
+            });
+        }
+
+        let n = dense.cols();
+        let rhs = dense.as_slice();
+        let fill_row = |(i, out): (usize, &mut [T])| {
+            for k in self.row_ptrs[i]..self.row_ptrs[i + 1] {
+                let value = self.values[k];
+                let rhs_row = &rhs[self.col_indices[k] * n..][..n];
+                for (r, &x) in out.iter_mut().zip(rhs_row) {
+                    *r = *r + value * x;
+                }
+            }
+        };
+
+        let concurrent = self.concurrent || dense.is_concurrent();
+        let mut data = vec![T::default(); self.rows * n];
+        if concurrent {
+            data.par_chunks_mut(n).enumerate().for_each(fill_row);
+        } else {
+            data.chunks_mut(n).enumerate().for_each(fill_row);
+        }
+
+        let mut result = Matrix::from_vec(self.rows, n, data)?;
+        result.set_concurrent(concurrent);
+        Ok(result)
+    }
+}
@@ -157,5 +157,73 @@
 //     Ok(())
 // }
 
-fn main()
-{}
\ No newline at end of file
+use neural_network::*;
+use matrix::Matrix;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Global allocator that counts allocations so the forward-pass benchmark can report them
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The previous forward pass: clones each bias and adds it through the consuming `Add` impl
+fn forward_cloning_biases<A: ActivationFunction<f64>>(
+    network: &NeuralNetwork<f64, A>,
+    input: &Matrix<f64>,
+) -> NeuralNetworkResult<Matrix<f64>> {
+    let mut current_output = input.clone();
+    for layer_idx in 0..network.num_layers() {
+        let layer = network.get_layer(layer_idx)?;
+        let linear_output = layer.weights.matrix_multiply(&current_output)?;
+        let linear_with_bias = (linear_output + layer.biases.clone())?;
+        current_output = layer.activation.activate(&linear_with_bias)?;
+    }
+    Ok(current_output)
+}
+
+fn measure<F: FnMut() -> NeuralNetworkResult<Matrix<f64>>>(name: &str, iterations: usize, mut f: F) -> NeuralNetworkResult<()> {
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f()?;
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+
+    println!("   {:<22} {:>8.2?} | {:>6.1} allocations/forward",
+        name, elapsed, allocations as f64 / iterations as f64);
+    Ok(())
+}
+
+fn main() -> NeuralNetworkResult<()> {
+    println!("=== Forward pass allocation microbenchmark ===\n");
+
+    // Sequential mode keeps the allocation count independent of rayon's scheduling
+    let mut network = nn![64, 128, 64, 10; Sigmoid]?;
+    network.set_concurrent(false);
+    network.xavier_initialization()?;
+
+    let input = Matrix::from_vec_sequential(64, 1, (0..64).map(|i| i as f64 / 64.0).collect())?;
+    let iterations = 1000;
+
+    measure("clone + add bias", iterations, || forward_cloning_biases(&network, &input))?;
+    measure("in-place bias", iterations, || network.forward(&input))?;
+
+    Ok(())
+}
@@ -9,6 +9,20 @@ where
     fn activate(&self, input: &Matrix<T>) -> NeuralNetworkResult<Matrix<T>>;
     fn derivative(&self, input: &Matrix<T>) -> NeuralNetworkResult<Matrix<T>>;
     fn name(&self) -> &'static str;
+
+    /// Apply the activation to `input` in place, without allocating a result matrix.
+    /// The default falls back to `activate`; the common activations override it.
+    fn activate_inplace(&self, input: &mut Matrix<T>) -> NeuralNetworkResult<()> {
+        *input = self.activate(input)?;
+        Ok(())
+    }
+
+    /// Gradient of the loss with respect to the activation's own trainable parameters, given the
+    /// pre-activation `input` and the loss gradient at the activation's output. Activations
+    /// without parameters return `None`.
+    fn parameter_gradient(&self, _input: &Matrix<T>, _output_gradient: &Matrix<T>) -> NeuralNetworkResult<Option<Matrix<T>>> {
+        Ok(None)
+    }
 }
 
 /// Sigmoid activation function
@@ -52,6 +66,11 @@ impl ActivationFunction<f64> for Sigmoid {
     fn name(&self) -> &'static str {
         "sigmoid"
     }
+
+    fn activate_inplace(&self, input: &mut Matrix<f64>) -> NeuralNetworkResult<()> {
+        input.as_mut_slice().iter_mut().for_each(|val| *val = 1.0 / (1.0 + (-*val).exp()));
+        Ok(())
+    }
 }
 
 /// ReLU activation function
@@ -92,6 +111,11 @@ impl ActivationFunction<f64> for ReLU {
     fn name(&self) -> &'static str {
         "relu"
     }
+
+    fn activate_inplace(&self, input: &mut Matrix<f64>) -> NeuralNetworkResult<()> {
+        input.as_mut_slice().iter_mut().for_each(|val| *val = val.max(0.0));
+        Ok(())
+    }
 }
 
 /// Tanh activation function
@@ -133,6 +157,11 @@ impl ActivationFunction<f64> for Tanh {
     fn name(&self) -> &'static str {
         "tanh"
     }
+
+    fn activate_inplace(&self, input: &mut Matrix<f64>) -> NeuralNetworkResult<()> {
+        input.as_mut_slice().iter_mut().for_each(|val| *val = val.tanh());
+        Ok(())
+    }
 }
 
 /// Linear activation function (identity)
@@ -206,4 +235,153 @@ impl ActivationFunction<f64> for LeakyReLU {
     fn name(&self) -> &'static str {
         "leaky_relu"
     }
-}
\ No newline at end of file
+}
+
+/// Parametric ReLU: Leaky ReLU with a trainable slope per channel (row) of the input
+#[derive(Debug, Clone)]
+pub struct PReLU {
+    /// One slope per channel, as a `channels x 1` column
+    pub alpha: Matrix<f64>,
+}
+
+impl PReLU {
+    pub fn new(channels: usize, initial_alpha: f64) -> NeuralNetworkResult<Self> {
+        Ok(Self { alpha: Matrix::fill(channels, 1, initial_alpha)? })
+    }
+
+    fn check_channels(&self, input: &Matrix<f64>) -> NeuralNetworkResult<()> {
+        if input.rows() != self.alpha.rows() {
+            return Err(NeuralNetworkError::InvalidInputSize {
+                expected: self.alpha.rows(),
+                actual: input.rows(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl ActivationFunction<f64> for PReLU {
+    fn activate(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        self.check_channels(input)?;
+        let (rows, cols) = input.dimensions();
+        let mut result = Matrix::new(rows, cols)?;
+        result.set_concurrent(input.is_concurrent());
+
+        for i in 0..rows {
+            let alpha = *self.alpha.get(i, 0)?;
+            for j in 0..cols {
+                let val = *input.get(i, j)?;
+                result.set(i, j, if val > 0.0 { val } else { alpha * val })?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn derivative(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        self.check_channels(input)?;
+        let (rows, cols) = input.dimensions();
+        let mut result = Matrix::new(rows, cols)?;
+        result.set_concurrent(input.is_concurrent());
+
+        for i in 0..rows {
+            let alpha = *self.alpha.get(i, 0)?;
+            for j in 0..cols {
+                let val = *input.get(i, j)?;
+                result.set(i, j, if val > 0.0 { 1.0 } else { alpha })?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn name(&self) -> &'static str {
+        "prelu"
+    }
+
+    /// d(loss)/d(alpha_i) = sum over the non-positive inputs of channel i of input * output gradient
+    fn parameter_gradient(&self, input: &Matrix<f64>, output_gradient: &Matrix<f64>) -> NeuralNetworkResult<Option<Matrix<f64>>> {
+        self.check_channels(input)?;
+        if output_gradient.dimensions() != input.dimensions() {
+            return Err(NeuralNetworkError::InvalidOutputSize {
+                expected: input.rows(),
+                actual: output_gradient.rows(),
+            });
+        }
+
+        let (rows, cols) = input.dimensions();
+        let mut gradient = Matrix::new(rows, 1)?;
+        gradient.set_concurrent(input.is_concurrent());
+
+        for i in 0..rows {
+            let mut sum = 0.0;
+            for j in 0..cols {
+                let val = *input.get(i, j)?;
+                if val <= 0.0 {
+                    sum += val * output_gradient.get(i, j)?;
+                }
+            }
+            gradient.set(i, 0, sum)?;
+        }
+
+        Ok(Some(gradient))
+    }
+}
+
+/// Any of the built-in activation functions, so layers of one network can use different ones
+#[derive(Debug, Clone)]
+pub enum Activation {
+    Sigmoid(Sigmoid),
+    ReLU(ReLU),
+    Tanh(Tanh),
+    Linear(Linear),
+    LeakyReLU(LeakyReLU),
+    PReLU(PReLU),
+}
+
+macro_rules! dispatch {
+    ($self:ident, $f:ident => $call:expr) => {
+        match $self {
+            Activation::Sigmoid($f) => $call,
+            Activation::ReLU($f) => $call,
+            Activation::Tanh($f) => $call,
+            Activation::Linear($f) => $call,
+            Activation::LeakyReLU($f) => $call,
+            Activation::PReLU($f) => $call,
+        }
+    };
+}
+
+impl ActivationFunction<f64> for Activation {
+    fn activate(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        dispatch!(self, f => f.activate(input))
+    }
+
+    fn derivative(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        dispatch!(self, f => f.derivative(input))
+    }
+
+    fn name(&self) -> &'static str {
+        dispatch!(self, f => f.name())
+    }
+
+    fn activate_inplace(&self, input: &mut Matrix<f64>) -> NeuralNetworkResult<()> {
+        dispatch!(self, f => f.activate_inplace(input))
+    }
+
+    fn parameter_gradient(&self, input: &Matrix<f64>, output_gradient: &Matrix<f64>) -> NeuralNetworkResult<Option<Matrix<f64>>> {
+        dispatch!(self, f => f.parameter_gradient(input, output_gradient))
+    }
+}
+
+macro_rules! impl_from_activation {
+    ($($variant:ident),+) => {
+        $(impl From<$variant> for Activation {
+            fn from(activation: $variant) -> Self {
+                Activation::$variant(activation)
+            }
+        })+
+    };
+}
+
+impl_from_activation!(Sigmoid, ReLU, Tanh, Linear, LeakyReLU, PReLU);
@@ -8,18 +8,27 @@ pub trait CostFunction: Send + Sync + Clone {
     fn name(&self) -> &'static str;
 }
 
+/// Shared validation: matching shapes and at least one element to average over
+fn check_dimensions(predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<()> {
+    if predicted.dimensions() != actual.dimensions() {
+        return Err(NeuralNetworkError::InvalidOutputSize {
+            expected: predicted.rows(),
+            actual: actual.rows(),
+        });
+    }
+    if predicted.is_empty() {
+        return Err(NeuralNetworkError::EmptyDataset);
+    }
+    Ok(())
+}
+
 /// Mean Squared Error cost function
 #[derive(Debug, Clone)]
 pub struct MeanSquaredError;
 
 impl CostFunction for MeanSquaredError {
     fn cost(&self, predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<f64> {
-        if predicted.dimensions() != actual.dimensions() {
-            return Err(NeuralNetworkError::InvalidOutputSize {
-                expected: predicted.rows(),
-                actual: actual.rows(),
-            });
-        }
+        check_dimensions(predicted, actual)?;
 
         let mut sum = 0.0;
         let (rows, cols) = predicted.dimensions();
@@ -35,12 +44,7 @@ impl CostFunction for MeanSquaredError {
     }
 
     fn derivative(&self, predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
-        if predicted.dimensions() != actual.dimensions() {
-            return Err(NeuralNetworkError::InvalidOutputSize {
-                expected: predicted.rows(),
-                actual: actual.rows(),
-            });
-        }
+        check_dimensions(predicted, actual)?;
 
         let (rows, cols) = predicted.dimensions();
         let mut result = Matrix::new(rows, cols)?;
@@ -67,19 +71,14 @@ pub struct CrossEntropy;
 
 impl CostFunction for CrossEntropy {
     fn cost(&self, predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<f64> {
-        if predicted.dimensions() != actual.dimensions() {
-            return Err(NeuralNetworkError::InvalidOutputSize {
-                expected: predicted.rows(),
-                actual: actual.rows(),
-            });
-        }
+        check_dimensions(predicted, actual)?;
 
         let mut sum = 0.0;
         let (rows, cols) = predicted.dimensions();
         
         for i in 0..rows {
             for j in 0..cols {
-                let p = predicted.get(i, j)?.max(1e-15).min(1.0 - 1e-15); // Prevent log(0)
+                let p = predicted.get(i, j)?.clamp(1e-15, 1.0 - 1e-15); // Prevent log(0)
                 let a = *actual.get(i, j)?;
                 sum += -(a * p.ln() + (1.0 - a) * (1.0 - p).ln());
             }
@@ -89,12 +88,7 @@ impl CostFunction for CrossEntropy {
     }
 
     fn derivative(&self, predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
-        if predicted.dimensions() != actual.dimensions() {
-            return Err(NeuralNetworkError::InvalidOutputSize {
-                expected: predicted.rows(),
-                actual: actual.rows(),
-            });
-        }
+        check_dimensions(predicted, actual)?;
 
         let (rows, cols) = predicted.dimensions();
         let mut result = Matrix::new(rows, cols)?;
@@ -102,7 +96,7 @@ impl CostFunction for CrossEntropy {
 
         for i in 0..rows {
             for j in 0..cols {
-                let p = predicted.get(i, j)?.max(1e-15).min(1.0 - 1e-15);
+                let p = predicted.get(i, j)?.clamp(1e-15, 1.0 - 1e-15);
                 let a = *actual.get(i, j)?;
                 let derivative_val = -(a / p - (1.0 - a) / (1.0 - p)) / (rows as f64 * cols as f64);
                 result.set(i, j, derivative_val)?;
@@ -123,12 +117,7 @@ pub struct MeanAbsoluteError;
 
 impl CostFunction for MeanAbsoluteError {
     fn cost(&self, predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<f64> {
-        if predicted.dimensions() != actual.dimensions() {
-            return Err(NeuralNetworkError::InvalidOutputSize {
-                expected: predicted.rows(),
-                actual: actual.rows(),
-            });
-        }
+        check_dimensions(predicted, actual)?;
 
         let mut sum = 0.0;
         let (rows, cols) = predicted.dimensions();
@@ -144,12 +133,7 @@ impl CostFunction for MeanAbsoluteError {
     }
 
     fn derivative(&self, predicted: &Matrix<f64>, actual: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
-        if predicted.dimensions() != actual.dimensions() {
-            return Err(NeuralNetworkError::InvalidOutputSize {
-                expected: predicted.rows(),
-                actual: actual.rows(),
-            });
-        }
+        check_dimensions(predicted, actual)?;
 
         let (rows, cols) = predicted.dimensions();
         let mut result = Matrix::new(rows, cols)?;
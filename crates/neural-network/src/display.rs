@@ -1,4 +1,4 @@
-use crate::nn::{NeuralNetwork, Layer};
+use crate::nn::NeuralNetwork;
 use crate::activation::ActivationFunction;
 use std::fmt;
 
@@ -11,7 +11,10 @@ pub enum NeuralNetworkError {
     ForwardPropagationError(String),
     InvalidActivationFunction,
     EmptyNetwork,
+    EmptyDataset,
     LayerIndexOutOfBounds { index: usize, max: usize },
+    InvalidParameterCount { expected: usize, actual: usize },
+    InvalidTrainingConfig(String),
 }
 
 impl fmt::Display for NeuralNetworkError {
@@ -41,14 +44,30 @@ impl fmt::Display for NeuralNetworkError {
             NeuralNetworkError::EmptyNetwork => {
                 write!(f, "Neural network is empty")
             }
+            NeuralNetworkError::EmptyDataset => {
+                write!(f, "Dataset is empty")
+            }
             NeuralNetworkError::LayerIndexOutOfBounds { index, max } => {
                 write!(f, "Layer index {} out of bounds (max: {})", index, max)
             }
+            NeuralNetworkError::InvalidParameterCount { expected, actual } => {
+                write!(f, "Invalid parameter count: expected {}, got {}", expected, actual)
+            }
+            NeuralNetworkError::InvalidTrainingConfig(msg) => {
+                write!(f, "Invalid training configuration: {}", msg)
+            }
         }
     }
 }
 
-impl std::error::Error for NeuralNetworkError {}
+impl std::error::Error for NeuralNetworkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NeuralNetworkError::MatrixError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<MatrixError> for NeuralNetworkError {
     fn from(error: MatrixError) -> Self {
@@ -0,0 +1,47 @@
+use matrix::Matrix;
+use tensor::Tensor;
+use tensor::simd::SIMDOps;
+use crate::error::{NeuralNetworkError, NeuralNetworkResult};
+
+/// Weight matrices with at least this many elements are multiplied through the tensor crate
+pub const SIMD_MATMUL_THRESHOLD: usize = 64 * 64;
+
+/// Convert a matrix into a rank-2 f32 tensor (values are narrowed to f32)
+pub fn matrix_to_tensor(matrix: &Matrix<f64>) -> NeuralNetworkResult<Tensor> {
+    let (rows, cols) = matrix.dimensions();
+    let mut data = Vec::with_capacity(rows * cols);
+    for i in 0..rows {
+        data.extend(matrix.row(i)?.into_iter().map(|x| x as f32));
+    }
+    Tensor::new(data, &[rows, cols]).map_err(tensor_error)
+}
+
+/// Convert a rank-2 tensor back into a matrix
+pub fn tensor_to_matrix(tensor: &Tensor, concurrent: bool) -> NeuralNetworkResult<Matrix<f64>> {
+    if tensor.rank() != 2 {
+        return Err(NeuralNetworkError::ForwardPropagationError(format!(
+            "Expected a rank-2 tensor, got shape {:?}",
+            tensor.shape()
+        )));
+    }
+
+    let data = tensor.data().iter().map(|&x| x as f64).collect();
+    let mut matrix = Matrix::from_vec(tensor.rows(), tensor.cols(), data)?;
+    matrix.set_concurrent(concurrent);
+    Ok(matrix)
+}
+
+/// Multiply two matrices with the tensor crate's multithreaded AVX2 kernel
+pub fn simd_matrix_multiply(a: &Matrix<f64>, b: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+    let product = SIMDOps::matrix_multiply_parallel(
+        &matrix_to_tensor(a)?,
+        &matrix_to_tensor(b)?,
+        rayon::current_num_threads(),
+    )
+    .map_err(tensor_error)?;
+    tensor_to_matrix(&product, a.is_concurrent() || b.is_concurrent())
+}
+
+fn tensor_error(error: tensor::TensorError) -> NeuralNetworkError {
+    NeuralNetworkError::ForwardPropagationError(error.to_string())
+}
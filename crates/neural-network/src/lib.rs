@@ -4,6 +4,8 @@ pub mod nn;
 pub mod training;
 pub mod display;
 pub mod cost;
+#[cfg(feature = "tensor-simd")]
+pub mod interop;
 
 pub use error::{NeuralNetworkError, NeuralNetworkResult};
 pub use activation::*;
@@ -12,6 +14,753 @@ pub use nn::*;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cost::{CostFunction, MeanSquaredError};
+    use crate::training::{TrainingAlgorithm, TrainingConfig, SGD};
     use matrix::Matrix;
-    
-}
\ No newline at end of file
+
+    // Large enough that the sample variance of the first layer is within a few percent
+    const FAN_IN: usize = 400;
+    const FAN_OUT: usize = 300;
+
+    fn large_network() -> NeuralNetwork<f64, Linear> {
+        NeuralNetwork::new(vec![FAN_IN, FAN_OUT], Linear, true).unwrap()
+    }
+
+    fn weight_variance(network: &NeuralNetwork<f64, Linear>) -> f64 {
+        let weights = &network.get_layer(0).unwrap().weights;
+        let (rows, cols) = weights.dimensions();
+        let n = (rows * cols) as f64;
+
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for i in 0..rows {
+            for j in 0..cols {
+                let w = *weights.get(i, j).unwrap();
+                sum += w;
+                sum_sq += w * w;
+            }
+        }
+        let mean = sum / n;
+        sum_sq / n - mean * mean
+    }
+
+    fn assert_variance_close(actual: f64, expected: f64) {
+        let relative_error = (actual - expected).abs() / expected;
+        assert!(
+            relative_error < 0.05,
+            "variance {} differs from theoretical {} by {:.2}%",
+            actual, expected, relative_error * 100.0
+        );
+    }
+
+    #[test]
+    fn test_xavier_uniform_variance() {
+        let mut nn = large_network();
+        nn.xavier_initialization().unwrap();
+        assert_variance_close(weight_variance(&nn), 2.0 / (FAN_IN + FAN_OUT) as f64);
+    }
+
+    #[test]
+    fn test_xavier_normal_variance() {
+        let mut nn = large_network();
+        nn.xavier_normal_initialization().unwrap();
+        assert_variance_close(weight_variance(&nn), 2.0 / (FAN_IN + FAN_OUT) as f64);
+    }
+
+    #[test]
+    fn test_he_normal_variance() {
+        let mut nn = large_network();
+        nn.he_initialization().unwrap();
+        assert_variance_close(weight_variance(&nn), 2.0 / FAN_IN as f64);
+    }
+
+    #[test]
+    fn test_he_uniform_variance() {
+        let mut nn = large_network();
+        nn.set_concurrent(false);
+        nn.he_uniform_initialization().unwrap();
+        assert_variance_close(weight_variance(&nn), 2.0 / FAN_IN as f64);
+    }
+
+    #[test]
+    fn test_lecun_variance() {
+        let mut nn = large_network();
+        nn.lecun_initialization().unwrap();
+        assert_variance_close(weight_variance(&nn), 1.0 / FAN_IN as f64);
+    }
+
+    #[test]
+    fn test_initialization_zeroes_biases() {
+        let mut nn = nn![4, 8, 2; ReLU].unwrap();
+        nn.random_initialization(-1.0, 1.0).unwrap();
+        nn.he_uniform_initialization().unwrap();
+        for layer_idx in 0..nn.num_layers() {
+            let biases = &nn.get_layer(layer_idx).unwrap().biases;
+            for i in 0..biases.rows() {
+                assert_eq!(*biases.get(i, 0).unwrap(), 0.0);
+            }
+        }
+    }
+
+    fn set_weights<A: ActivationFunction<f64>>(network: &mut NeuralNetwork<f64, A>, values: &[&[f64]]) {
+        for (layer_idx, layer_values) in values.iter().enumerate() {
+            let layer = network.get_layer_mut(layer_idx).unwrap();
+            let cols = layer.weights.cols();
+            let weight_count = layer.weights.rows() * cols;
+            for (k, &value) in layer_values.iter().enumerate() {
+                if k < weight_count {
+                    layer.weights.set(k / cols, k % cols, value).unwrap();
+                } else {
+                    layer.biases.set(k - weight_count, 0, value).unwrap();
+                }
+            }
+        }
+    }
+
+    /// Compares the weight update of a single SGD step against central finite differences
+    fn check_gradients<A: ActivationFunction<f64>>(network: NeuralNetwork<f64, A>) {
+        let input = Matrix::from_vec(2, 1, vec![0.7, -1.3]).unwrap();
+        let target = Matrix::from_vec(1, 1, vec![0.25]).unwrap();
+        let cost = MeanSquaredError;
+        let learning_rate = 1e-3;
+
+        let config = TrainingConfig {
+            learning_rate,
+            epochs: 1,
+            batch_size: 1,
+            validation_split: 0.0,
+            early_stopping_patience: None,
+            verbose: false,
+            ..TrainingConfig::default()
+        };
+        let mut trained = network.clone();
+        let mut sgd = SGD::new(MeanSquaredError);
+        sgd.train(&mut trained, std::slice::from_ref(&input), std::slice::from_ref(&target), &config).unwrap();
+
+        let epsilon = 1e-6;
+        for layer_idx in 0..network.num_layers() {
+            let weights = &network.get_layer(layer_idx).unwrap().weights;
+            for i in 0..weights.rows() {
+                for j in 0..weights.cols() {
+                    let original = *weights.get(i, j).unwrap();
+                    let updated = *trained.get_layer(layer_idx).unwrap().weights.get(i, j).unwrap();
+                    let analytic = (original - updated) / learning_rate;
+
+                    let mut shifted = network.clone();
+                    shifted.get_layer_mut(layer_idx).unwrap().weights.set(i, j, original + epsilon).unwrap();
+                    let cost_plus = cost.cost(&shifted.forward(&input).unwrap(), &target).unwrap();
+                    shifted.get_layer_mut(layer_idx).unwrap().weights.set(i, j, original - epsilon).unwrap();
+                    let cost_minus = cost.cost(&shifted.forward(&input).unwrap(), &target).unwrap();
+                    let numeric = (cost_plus - cost_minus) / (2.0 * epsilon);
+
+                    assert!(
+                        (analytic - numeric).abs() < 1e-6,
+                        "layer {} weight ({}, {}): analytic {} vs numeric {}",
+                        layer_idx, i, j, analytic, numeric
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_relu_gradients_match_finite_differences() {
+        let mut network = nn![2, 3, 1; ReLU].unwrap();
+        // The second hidden unit is inactive for the test input, so its path must get zero gradient
+        set_weights(&mut network, &[
+            &[0.5, -0.2, 0.9, 0.8, -0.3, 0.1, 0.1, 0.2, 0.3],
+            &[0.6, -0.4, 0.7, 0.05],
+        ]);
+        check_gradients(network);
+    }
+
+    #[test]
+    fn test_sigmoid_gradients_match_finite_differences() {
+        let mut network = nn![2, 3, 1; Sigmoid].unwrap();
+        set_weights(&mut network, &[
+            &[0.5, -0.2, 0.9, 0.8, -0.3, 0.1, 0.1, 0.2, 0.3],
+            &[0.6, -0.4, 0.7, 0.05],
+        ]);
+        check_gradients(network);
+    }
+
+    #[test]
+    fn test_forward_with_pre_activations() {
+        let mut network = nn![2, 2; Tanh].unwrap();
+        set_weights(&mut network, &[&[1.0, 2.0, -1.0, 0.5, 0.1, -0.1]]);
+        let input = Matrix::from_vec(2, 1, vec![1.0, 1.0]).unwrap();
+
+        let (activations, pre_activations) = network.forward_with_pre_activations(&input).unwrap();
+        assert_eq!(activations.len(), 2);
+        assert_eq!(pre_activations.len(), 1);
+        assert!((pre_activations[0][(0, 0)] - 3.1).abs() < 1e-12);
+        assert!((pre_activations[0][(1, 0)] + 0.6).abs() < 1e-12);
+        assert!((activations[1][(0, 0)] - 3.1f64.tanh()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_forward_matches_cloned_bias_addition() {
+        let mut network = nn![3, 5, 2; Tanh].unwrap();
+        network.xavier_initialization().unwrap();
+        let input = Matrix::from_vec(3, 1, vec![0.2, -0.7, 1.1]).unwrap();
+
+        let mut expected = input.clone();
+        for layer_idx in 0..network.num_layers() {
+            let layer = network.get_layer(layer_idx).unwrap();
+            let linear_output = layer.weights.matrix_multiply(&expected).unwrap();
+            let linear_with_bias = (linear_output + layer.biases.clone()).unwrap();
+            expected = layer.activation.activate(&linear_with_bias).unwrap();
+        }
+
+        let output = network.forward(&input).unwrap();
+        let intermediates = network.forward_with_intermediates(&input).unwrap();
+        for i in 0..2 {
+            assert_eq!(output[(i, 0)], expected[(i, 0)]);
+            assert_eq!(intermediates[2][(i, 0)], expected[(i, 0)]);
+        }
+    }
+
+    #[cfg(feature = "tensor-simd")]
+    #[test]
+    fn test_simd_routed_forward_matches_matrix_forward() {
+        let mut network = nn![100, 80, 10; Sigmoid].unwrap();
+        network.xavier_initialization().unwrap();
+        let input = Matrix::from_vec(100, 1, (0..100).map(|i| (i as f64 * 0.37).sin()).collect()).unwrap();
+
+        let mut expected = input.clone();
+        for layer_idx in 0..network.num_layers() {
+            let layer = network.get_layer(layer_idx).unwrap();
+            let linear = layer.weights.matrix_multiply(&expected).unwrap();
+            let linear_with_bias = (linear + layer.biases.clone()).unwrap();
+            expected = layer.activation.activate(&linear_with_bias).unwrap();
+        }
+
+        // The first layer (80x100) is above the threshold and goes through the tensor crate
+        const { assert!(80 * 100 >= crate::interop::SIMD_MATMUL_THRESHOLD) };
+        let output = network.forward(&input).unwrap();
+        for i in 0..10 {
+            assert!((output[(i, 0)] - expected[(i, 0)]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_train_with_callback_stops_early() {
+        let mut network = nn![2, 3, 1; Sigmoid].unwrap();
+        network.xavier_initialization().unwrap();
+        let inputs = vec![Matrix::from_vec(2, 1, vec![0.0, 1.0]).unwrap(); 4];
+        let targets = vec![Matrix::from_vec(1, 1, vec![1.0]).unwrap(); 4];
+        let config = TrainingConfig {
+            epochs: 50,
+            verbose: false,
+            early_stopping_patience: None,
+            ..TrainingConfig::default()
+        };
+
+        let mut seen = Vec::new();
+        let history = SGD::new(MeanSquaredError)
+            .train_with_callback(&mut network, &inputs, &targets, &config, |metric, net| {
+                assert_eq!(net.num_layers(), 2);
+                seen.push(metric.epoch);
+                metric.epoch < 3
+            })
+            .unwrap();
+
+        assert_eq!(history.metrics.len(), 3);
+        assert_eq!(seen, vec![1, 2, 3]);
+        assert!(!history.stopped_early);
+    }
+
+    #[test]
+    fn test_training_tracks_accuracy() {
+        let mut network = nn![2, 4, 2; Sigmoid].unwrap();
+        network.xavier_initialization().unwrap();
+
+        let mut inputs = Vec::new();
+        let mut targets = Vec::new();
+        for i in 0..20 {
+            let x = i as f64 / 10.0 - 1.0;
+            let class = usize::from(x > 0.0);
+            inputs.push(Matrix::from_vec(2, 1, vec![x, -x]).unwrap());
+            let mut target = vec![0.0, 0.0];
+            target[class] = 1.0;
+            targets.push(Matrix::from_vec(2, 1, target).unwrap());
+        }
+
+        let config = TrainingConfig {
+            epochs: 5,
+            learning_rate: 0.5,
+            batch_size: 4,
+            verbose: false,
+            early_stopping_patience: None,
+            track_accuracy: true,
+            ..TrainingConfig::default()
+        };
+        let history = SGD::new(MeanSquaredError).train(&mut network, &inputs, &targets, &config).unwrap();
+
+        assert_eq!(history.metrics.len(), 5);
+        for metric in &history.metrics {
+            let train = metric.train_accuracy.unwrap();
+            let validation = metric.validation_accuracy.unwrap();
+            assert!((0.0..=1.0).contains(&train));
+            assert!((0.0..=1.0).contains(&validation));
+        }
+
+        let untracked = TrainingConfig { track_accuracy: false, ..config };
+        let history = SGD::new(MeanSquaredError).train(&mut network, &inputs, &targets, &untracked).unwrap();
+        assert!(history.metrics.iter().all(|m| m.train_accuracy.is_none()));
+    }
+
+    #[test]
+    fn test_progress_line_shows_total_epochs() {
+        let metric = crate::training::TrainingMetrics {
+            epoch: 100,
+            train_loss: 0.5,
+            validation_loss: Some(0.25),
+            train_accuracy: None,
+            validation_accuracy: None,
+            epoch_duration: std::time::Duration::from_millis(3),
+            total_duration: std::time::Duration::from_millis(300),
+        };
+
+        let line = SGD::new(MeanSquaredError).format_progress(&metric, 1000, 0.01);
+        assert!(line.starts_with("Epoch  100/1000 |"), "{}", line);
+        assert!(line.contains("LR: 1.00e-2"), "{}", line);
+        assert!(!line.contains('?'));
+    }
+
+    #[test]
+    fn test_forward_accepts_row_vectors() {
+        let mut network = nn![3, 4, 2; Tanh].unwrap();
+        network.xavier_initialization().unwrap();
+        let column = Matrix::from_vec(3, 1, vec![0.3, -0.8, 1.2]).unwrap();
+        let row = Matrix::from_vec(1, 3, vec![0.3, -0.8, 1.2]).unwrap();
+
+        let from_column = network.forward(&column).unwrap();
+        let from_row = network.forward(&row).unwrap();
+        assert_eq!(from_row.dimensions(), (2, 1));
+        for i in 0..2 {
+            assert_eq!(from_row[(i, 0)], from_column[(i, 0)]);
+        }
+
+        let intermediates = network.forward_with_intermediates(&row).unwrap();
+        assert_eq!(intermediates[0].dimensions(), (3, 1));
+
+        let wrong = Matrix::from_vec(1, 4, vec![0.0; 4]).unwrap();
+        assert!(matches!(
+            network.forward(&wrong),
+            Err(NeuralNetworkError::InvalidInputSize { expected: 3, actual: 4 })
+        ));
+        assert!(network.forward(&Matrix::<f64>::new(3, 2).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_parameters_round_trip() {
+        let mut network = nn![3, 4, 2; Tanh].unwrap();
+        network.xavier_initialization().unwrap();
+        let params = network.parameters();
+        assert_eq!(params.len(), network.parameter_count());
+        assert_eq!(params[0], network.get_layer(0).unwrap().weights[(0, 0)]);
+
+        let input = Matrix::from_vec(3, 1, vec![0.5, -0.1, 0.9]).unwrap();
+        let before = network.forward(&input).unwrap();
+        network.set_parameters(&params).unwrap();
+        assert_eq!(network.parameters(), params);
+        let after = network.forward(&input).unwrap();
+        for i in 0..2 {
+            assert_eq!(before[(i, 0)], after[(i, 0)]);
+        }
+
+        let mut shifted = params.clone();
+        shifted[12] += 1.0;
+        network.set_parameters(&shifted).unwrap();
+        assert_eq!(network.get_layer(0).unwrap().biases[(0, 0)], params[12] + 1.0);
+
+        assert!(matches!(
+            network.set_parameters(&params[1..]),
+            Err(NeuralNetworkError::InvalidParameterCount { expected: 26, actual: 25 })
+        ));
+    }
+
+    #[test]
+    fn test_training_without_validation_split() {
+        let mut network = nn![2, 3, 1; Sigmoid].unwrap();
+        network.xavier_initialization().unwrap();
+        let inputs = vec![Matrix::from_vec(2, 1, vec![0.0, 1.0]).unwrap(); 4];
+        let targets = vec![Matrix::from_vec(1, 1, vec![1.0]).unwrap(); 4];
+        let config = TrainingConfig {
+            epochs: 20,
+            validation_split: 0.0,
+            early_stopping_patience: Some(1),
+            min_improvement: f64::INFINITY,
+            track_accuracy: true,
+            log_interval: 10,
+            ..TrainingConfig::default()
+        };
+
+        let mut sgd = SGD::new(MeanSquaredError);
+        let history = sgd.train(&mut network, &inputs, &targets, &config).unwrap();
+        assert_eq!(history.metrics.len(), 20);
+        assert!(!history.stopped_early);
+        assert!(history.best_validation_loss.is_none());
+        assert!(history.metrics.iter().all(|m| m.validation_loss.is_none()));
+        assert!(history.metrics.iter().all(|m| m.validation_accuracy.is_none()));
+
+        let line = sgd.format_progress(&history.metrics[0], 20, 0.01);
+        assert!(!line.contains("Val Loss"), "{}", line);
+    }
+
+    #[test]
+    fn test_batch_size_validation() {
+        let mut network = nn![2, 3, 1; Sigmoid].unwrap();
+        network.xavier_initialization().unwrap();
+        let inputs = vec![Matrix::from_vec(2, 1, vec![0.5, 1.0]).unwrap(); 3];
+        let targets = vec![Matrix::from_vec(1, 1, vec![1.0]).unwrap(); 3];
+        let mut config = TrainingConfig {
+            epochs: 5,
+            batch_size: 0,
+            validation_split: 0.0,
+            verbose: false,
+            ..TrainingConfig::default()
+        };
+
+        let mut sgd = SGD::new(MeanSquaredError);
+        assert!(matches!(
+            sgd.train(&mut network, &inputs, &targets, &config),
+            Err(NeuralNetworkError::InvalidTrainingConfig(_))
+        ));
+
+        // A batch bigger than the data set is one full-batch step per epoch
+        let mut full_batch = network.clone();
+        config.batch_size = 3;
+        let exact = sgd.train(&mut full_batch, &inputs, &targets, &config).unwrap();
+        config.batch_size = 100;
+        let clamped = sgd.train(&mut network, &inputs, &targets, &config).unwrap();
+        assert_eq!(clamped.metrics.len(), 5);
+        assert_eq!(network.parameters(), full_batch.parameters());
+        assert_eq!(clamped.metrics[4].train_loss, exact.metrics[4].train_loss);
+    }
+
+    #[test]
+    fn test_validate_detailed_per_sample_losses() {
+        let mut network = nn![2, 3, 1; Sigmoid].unwrap();
+        network.xavier_initialization().unwrap();
+        let inputs = vec![
+            Matrix::from_vec(2, 1, vec![0.0, 1.0]).unwrap(),
+            Matrix::from_vec(2, 1, vec![1.0, 0.0]).unwrap(),
+            Matrix::from_vec(2, 1, vec![1.0, 1.0]).unwrap(),
+        ];
+        let targets = vec![
+            Matrix::from_vec(1, 1, vec![1.0]).unwrap(),
+            Matrix::from_vec(1, 1, vec![0.0]).unwrap(),
+            Matrix::from_vec(1, 1, vec![1.0]).unwrap(),
+        ];
+
+        let sgd = SGD::new(MeanSquaredError);
+        let losses = sgd.validate_detailed(&network, &inputs, &targets).unwrap();
+        assert_eq!(losses.len(), 3);
+        let prediction = network.forward(&inputs[1]).unwrap();
+        assert_eq!(losses[1], MeanSquaredError.cost(&prediction, &targets[1]).unwrap());
+
+        let mean = losses.iter().sum::<f64>() / 3.0;
+        let aggregate = sgd.validate(&network, &inputs, &targets).unwrap();
+        assert!((mean - aggregate).abs() < 1e-12);
+        assert!(sgd.validate_detailed(&network, &inputs, &targets[..2]).is_err());
+    }
+
+    #[test]
+    fn test_seeded_initialization_is_reproducible() {
+        let mut first = nn![4, 6, 3; Tanh].unwrap();
+        let mut second = nn![4, 6, 3; Tanh].unwrap();
+        first.xavier_initialization_seeded(42).unwrap();
+        second.xavier_initialization_seeded(42).unwrap();
+        assert_eq!(first.parameters(), second.parameters());
+
+        // Per-layer seeds make the result independent of the execution mode
+        second.set_concurrent(false);
+        second.xavier_initialization_seeded(42).unwrap();
+        assert_eq!(first.parameters(), second.parameters());
+
+        second.xavier_initialization_seeded(43).unwrap();
+        assert_ne!(first.parameters(), second.parameters());
+
+        first.random_initialization_seeded(-0.5, 0.5, 7).unwrap();
+        second.random_initialization_seeded(-0.5, 0.5, 7).unwrap();
+        assert_eq!(first.parameters(), second.parameters());
+        assert!(first.parameters().iter().all(|p| (-0.5..0.5).contains(p)));
+    }
+
+    #[test]
+    fn test_training_history_timing_stats() {
+        use crate::training::{TrainingHistory, TrainingMetrics};
+        use std::time::Duration;
+
+        let mut history = TrainingHistory::new();
+        assert_eq!(history.total_time(), Duration::ZERO);
+        assert_eq!(history.average_epoch_time(), Duration::ZERO);
+        assert!(history.fastest_epoch().is_none());
+
+        let mut total = Duration::ZERO;
+        for (epoch, millis) in [(1, 30), (2, 10), (3, 20)] {
+            total += Duration::from_millis(millis + 1);
+            history.add_metric(TrainingMetrics {
+                epoch,
+                train_loss: 1.0,
+                validation_loss: None,
+                train_accuracy: None,
+                validation_accuracy: None,
+                epoch_duration: Duration::from_millis(millis),
+                total_duration: total,
+            });
+        }
+
+        assert_eq!(history.total_time(), Duration::from_millis(63));
+        assert_eq!(history.average_epoch_time(), Duration::from_millis(20));
+        assert_eq!(history.fastest_epoch().unwrap().epoch, 2);
+    }
+
+    #[test]
+    fn test_predict_returns_dominant_class() {
+        let mut network = nn![2, 3; Sigmoid].unwrap();
+        let layer = network.get_layer_mut(0).unwrap();
+        layer.weights = Matrix::from_vec(3, 2, vec![-4.0, 0.0, 0.0, 4.0, 0.5, 0.5]).unwrap();
+        layer.biases = Matrix::from_vec(3, 1, vec![0.0; 3]).unwrap();
+
+        let input = Matrix::from_vec(2, 1, vec![1.0, 2.0]).unwrap();
+        assert_eq!(network.predict(&input).unwrap(), 1);
+        let proba = network.predict_proba(&input).unwrap();
+        assert_eq!(proba.dimensions(), (3, 1));
+        assert!(proba[(1, 0)] > proba[(2, 0)] && proba[(2, 0)] > proba[(0, 0)]);
+
+        let row_input = Matrix::from_vec(1, 2, vec![-1.0, 0.0]).unwrap();
+        assert_eq!(network.predict(&row_input).unwrap(), 0);
+        assert!(network.predict(&Matrix::<f64>::new(3, 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_gradient_accumulation_matches_larger_batch() {
+        let mut network = nn![3, 4, 2; Tanh].unwrap();
+        network.xavier_initialization_seeded(5).unwrap();
+        let inputs: Vec<Matrix<f64>> = (0..32)
+            .map(|i| Matrix::from_vec(3, 1, vec![i as f64 / 32.0, (i % 5) as f64 * 0.2, -0.5]).unwrap())
+            .collect();
+        let targets: Vec<Matrix<f64>> = (0..32)
+            .map(|i| Matrix::from_vec(2, 1, vec![(i % 2) as f64, 1.0 - (i % 2) as f64]).unwrap())
+            .collect();
+        let full_batch = TrainingConfig {
+            epochs: 3,
+            learning_rate: 0.1,
+            batch_size: 32,
+            validation_split: 0.0,
+            early_stopping_patience: None,
+            verbose: false,
+            ..TrainingConfig::default()
+        };
+        let accumulated = TrainingConfig { batch_size: 16, accumulation_steps: 2, ..full_batch.clone() };
+
+        let mut sgd = SGD::new(MeanSquaredError);
+        let mut reference = network.clone();
+        sgd.train(&mut reference, &inputs, &targets, &full_batch).unwrap();
+        sgd.train(&mut network, &inputs, &targets, &accumulated).unwrap();
+        for (a, b) in network.parameters().iter().zip(reference.parameters()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let invalid = TrainingConfig { accumulation_steps: 0, ..full_batch };
+        assert!(matches!(
+            sgd.train(&mut network, &inputs, &targets, &invalid),
+            Err(NeuralNetworkError::InvalidTrainingConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_nn_macro_with_per_layer_activations() {
+        let mut network = nn![2, 4 => ReLU, 3 => Tanh, 1 => Sigmoid].unwrap();
+        assert_eq!(network.architecture(), &[2, 4, 3, 1]);
+        let names: Vec<&str> = (0..network.num_layers())
+            .map(|i| network.get_layer(i).unwrap().activation.name())
+            .collect();
+        assert_eq!(names, vec![ReLU.name(), Tanh.name(), Sigmoid.name()]);
+
+        network.xavier_initialization_seeded(3).unwrap();
+        let output = network.forward(&Matrix::from_vec(2, 1, vec![0.5, -0.5]).unwrap()).unwrap();
+        assert_eq!(output.dimensions(), (1, 1));
+        assert!(output[(0, 0)] > 0.0 && output[(0, 0)] < 1.0);
+
+        let leaky = nn![3, 2 => LeakyReLU::new(0.1)].unwrap();
+        assert_eq!(leaky.get_layer(0).unwrap().activation.name(), "leaky_relu");
+        assert!(NeuralNetwork::with_activations(vec![2, 3, 1], vec![Activation::from(ReLU)], true).is_err());
+    }
+
+    #[test]
+    fn test_empty_datasets_are_rejected() {
+        let mut network = nn![2, 1; Sigmoid].unwrap();
+        let mut sgd = SGD::new(MeanSquaredError);
+        assert!(matches!(sgd.validate(&network, &[], &[]), Err(NeuralNetworkError::EmptyDataset)));
+        assert!(sgd.validate_detailed(&network, &[], &[]).unwrap().is_empty());
+
+        let config = TrainingConfig { epochs: 2, verbose: false, ..TrainingConfig::default() };
+        assert!(matches!(
+            sgd.train(&mut network, &[], &[], &config),
+            Err(NeuralNetworkError::EmptyDataset)
+        ));
+
+        // Everything held out for validation leaves nothing to train on
+        let inputs = vec![Matrix::from_vec(2, 1, vec![1.0, 0.0]).unwrap(); 2];
+        let targets = vec![Matrix::from_vec(1, 1, vec![1.0]).unwrap(); 2];
+        let all_validation = TrainingConfig { validation_split: 1.0, ..config };
+        assert!(matches!(
+            sgd.train(&mut network, &inputs, &targets, &all_validation),
+            Err(NeuralNetworkError::EmptyDataset)
+        ));
+    }
+
+    #[test]
+    fn test_compute_gradients_match_finite_differences() {
+        let mut network = nn![2, 3, 1; Sigmoid].unwrap();
+        set_weights(&mut network, &[
+            &[0.5, -0.2, 0.9, 0.8, -0.3, 0.1, 0.1, 0.2, 0.3],
+            &[0.6, -0.4, 0.7, 0.05],
+        ]);
+        let inputs = vec![
+            Matrix::from_vec(2, 1, vec![0.7, -1.3]).unwrap(),
+            Matrix::from_vec(2, 1, vec![-0.4, 0.9]).unwrap(),
+        ];
+        let targets = vec![
+            Matrix::from_vec(1, 1, vec![0.25]).unwrap(),
+            Matrix::from_vec(1, 1, vec![0.8]).unwrap(),
+        ];
+        let cost = MeanSquaredError;
+        let original = network.parameters();
+
+        let (weight_gradients, bias_gradients) = network.compute_gradients(&inputs, &targets, &cost).unwrap();
+        assert_eq!(network.parameters(), original);
+        let analytic: Vec<f64> = weight_gradients.iter().zip(bias_gradients.iter())
+            .flat_map(|(weights, biases)| weights.as_slice().iter().chain(biases.as_slice()).copied())
+            .collect();
+
+        let mean_cost = |params: &[f64]| {
+            let mut shifted = network.clone();
+            shifted.set_parameters(params).unwrap();
+            let losses = SGD::new(MeanSquaredError).validate_detailed(&shifted, &inputs, &targets).unwrap();
+            losses.iter().sum::<f64>() / losses.len() as f64
+        };
+        let epsilon = 1e-6;
+        for (k, &expected) in analytic.iter().enumerate() {
+            let mut plus = original.clone();
+            plus[k] += epsilon;
+            let mut minus = original.clone();
+            minus[k] -= epsilon;
+            let numeric = (mean_cost(&plus) - mean_cost(&minus)) / (2.0 * epsilon);
+            assert!((expected - numeric).abs() < 1e-6, "parameter {}: analytic {} vs numeric {}", k, expected, numeric);
+        }
+
+        assert!(matches!(network.compute_gradients(&[], &[], &cost), Err(NeuralNetworkError::EmptyDataset)));
+    }
+
+    #[test]
+    fn test_wrapped_matrix_error_is_the_source() {
+        use std::error::Error;
+
+        let err: NeuralNetworkError = matrix::MatrixError::SingularMatrix.into();
+        let source = err.source().expect("matrix errors carry their cause");
+        assert_eq!(source.to_string(), matrix::MatrixError::SingularMatrix.to_string());
+        assert!(NeuralNetworkError::EmptyDataset.source().is_none());
+    }
+
+    #[test]
+    fn test_prelu_matches_leaky_relu_for_fixed_alpha() {
+        let input = Matrix::from_vec(3, 2, vec![1.5, -2.0, -0.5, 0.0, 3.0, -4.0]).unwrap();
+        let prelu = PReLU::new(3, 0.1).unwrap();
+        let leaky = LeakyReLU::new(0.1);
+
+        assert_eq!(prelu.activate(&input).unwrap(), leaky.activate(&input).unwrap());
+        assert_eq!(prelu.derivative(&input).unwrap(), leaky.derivative(&input).unwrap());
+        assert!(prelu.activate(&Matrix::new(2, 1).unwrap()).is_err());
+
+        // Only the non-positive inputs depend on alpha
+        let output_gradient = Matrix::from_vec(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let alpha_gradient = prelu.parameter_gradient(&input, &output_gradient).unwrap().unwrap();
+        assert_eq!(alpha_gradient, Matrix::from_vec(3, 1, vec![-4.0, -1.5, -24.0]).unwrap());
+        assert!(ReLU.parameter_gradient(&input, &output_gradient).unwrap().is_none());
+        assert_eq!(Activation::from(prelu).name(), "prelu");
+    }
+
+    #[test]
+    fn test_restore_best_keeps_best_epoch_weights() {
+        // The validation target contradicts the training targets, so validation loss only gets
+        // worse as training goes on and the best epoch is an early one
+        let inputs = vec![Matrix::from_vec(1, 1, vec![1.0]).unwrap(); 5];
+        let mut targets = vec![Matrix::from_vec(1, 1, vec![1.0]).unwrap(); 4];
+        targets.push(Matrix::from_vec(1, 1, vec![0.0]).unwrap());
+
+        let config = TrainingConfig {
+            learning_rate: 0.5,
+            epochs: 20,
+            batch_size: 4,
+            validation_split: 0.2,
+            early_stopping_patience: None,
+            restore_best: true,
+            verbose: false,
+            ..TrainingConfig::default()
+        };
+
+        let mut network = nn![1, 1; Sigmoid].unwrap();
+        network.xavier_initialization_seeded(3).unwrap();
+        let mut epoch_parameters = Vec::new();
+        let history = SGD::new(MeanSquaredError)
+            .train_with_callback(&mut network, &inputs, &targets, &config, |_, net| {
+                epoch_parameters.push(net.parameters());
+                true
+            })
+            .unwrap();
+
+        assert!(history.best_epoch < config.epochs);
+        assert_eq!(network.parameters(), epoch_parameters[history.best_epoch - 1]);
+        assert_ne!(network.parameters(), *epoch_parameters.last().unwrap());
+    }
+
+    #[test]
+    fn test_activate_inplace_matches_activate() {
+        let input = Matrix::from_vec(2, 3, vec![-2.0, -0.5, 0.0, 0.3, 1.0, 4.0]).unwrap();
+        let activations: Vec<Activation> = vec![
+            Sigmoid.into(), ReLU.into(), Tanh.into(), Linear.into(), LeakyReLU::new(0.2).into(),
+        ];
+
+        for activation in activations {
+            let mut in_place = input.clone();
+            activation.activate_inplace(&mut in_place).unwrap();
+            assert_eq!(in_place, activation.activate(&input).unwrap(), "{}", activation.name());
+        }
+    }
+
+    #[test]
+    fn test_clone_architecture_resets_weights() {
+        let mut original = nn![3, 4 => ReLU, 2 => Sigmoid].unwrap();
+        original.he_initialization_seeded(1).unwrap();
+
+        let mut copy = original.clone_architecture().unwrap();
+        assert_eq!(copy.architecture(), original.architecture());
+        assert_eq!(copy.is_concurrent(), original.is_concurrent());
+        for layer_idx in 0..original.num_layers() {
+            assert_eq!(copy.get_layer(layer_idx).unwrap().activation.name(), original.get_layer(layer_idx).unwrap().activation.name());
+        }
+        assert!(copy.parameters().iter().all(|&p| p == 0.0));
+
+        let before = original.parameters();
+        copy.xavier_initialization_seeded(2).unwrap();
+        assert_eq!(original.parameters(), before);
+        assert_ne!(copy.parameters(), before);
+    }
+
+    #[test]
+    fn test_clone_architecture_copies_activation_parameters() {
+        let mut original = NeuralNetwork::with_activations(vec![3, 4], vec![PReLU::new(4, 0.25).unwrap()], false).unwrap();
+        original.xavier_initialization_seeded(1).unwrap();
+        let trained_alpha = Matrix::from_vec(4, 1, vec![0.1, 0.2, 0.3, 0.4]).unwrap();
+        original.get_layer_mut(0).unwrap().activation.alpha = trained_alpha.clone();
+
+        let copy = original.clone_architecture().unwrap();
+        assert!(copy.parameters().iter().all(|&p| p == 0.0));
+        assert_eq!(copy.get_layer(0).unwrap().activation.alpha, trained_alpha);
+    }
+}
@@ -1,8 +1,11 @@
 use matrix::Matrix;
 use crate::activation::ActivationFunction;
+use crate::cost::CostFunction;
+use crate::training::{Gradients, SGD};
 use crate::error::{NeuralNetworkError, NeuralNetworkResult};
 use rand::prelude::*;
-use rand_distr::{Normal, Distribution};
+use rand_distr::{Normal, Uniform, Distribution};
+use rand_pcg::Pcg64;
 use rayon::prelude::*;
 
 /// Layer structure containing weights, biases, and activation function
@@ -52,6 +55,9 @@ where
     }
 }
 
+/// Post-activation outputs (input first) and pre-activation values of every layer
+pub type ForwardPass = (Vec<Matrix<f64>>, Vec<Matrix<f64>>);
+
 /// Neural Network structure
 #[derive(Debug, Clone)]
 pub struct NeuralNetwork<T, A>
@@ -70,25 +76,39 @@ where
 {
     /// Create a new neural network with given architecture
     pub fn new(architecture: Vec<usize>, activation: A, concurrent: bool) -> NeuralNetworkResult<Self> {
+        let activations = vec![activation; architecture.len().saturating_sub(1)];
+        Self::with_activations(architecture, activations, concurrent)
+    }
+
+    /// Create a network whose layer `i` (from `architecture[i]` to `architecture[i + 1]`)
+    /// uses `activations[i]`; see `Activation` for mixing the built-in functions
+    pub fn with_activations(architecture: Vec<usize>, activations: Vec<A>, concurrent: bool) -> NeuralNetworkResult<Self> {
         if architecture.len() < 2 {
             return Err(NeuralNetworkError::InvalidArchitecture(
                 "Network must have at least 2 layers (input and output)".to_string(),
             ));
         }
 
-        if architecture.iter().any(|&size| size == 0) {
+        if architecture.contains(&0) {
             return Err(NeuralNetworkError::InvalidArchitecture(
                 "Layer sizes must be greater than 0".to_string(),
             ));
         }
 
+        if activations.len() != architecture.len() - 1 {
+            return Err(NeuralNetworkError::InvalidArchitecture(format!(
+                "Expected {} activations for {} layers, got {}",
+                architecture.len() - 1, architecture.len(), activations.len()
+            )));
+        }
+
         let mut layers = Vec::new();
 
         // Create layers between consecutive sizes in architecture
-        for i in 0..architecture.len() - 1 {
+        for (i, activation) in activations.into_iter().enumerate() {
             let input_size = architecture[i];
             let output_size = architecture[i + 1];
-            let layer = Layer::new(input_size, output_size, activation.clone(), concurrent)?;
+            let layer = Layer::new(input_size, output_size, activation, concurrent)?;
             layers.push(layer);
         }
 
@@ -99,216 +119,338 @@ where
         })
     }
 
-    /// Initialize weights and biases with Xavier/Glorot initialization
-    pub fn xavier_initialization(&mut self) -> NeuralNetworkResult<()> {
-        if self.concurrent {
-            // Use parallel initialization with thread-local RNGs
-            self.layers.par_iter_mut().try_for_each(|layer| -> NeuralNetworkResult<()> {
-                let mut rng = rand::rng(); // Use the new rand::rng() function
-                let fan_in = layer.input_size() as f64;
-                let fan_out = layer.output_size() as f64;
-                let limit = (6.0 / (fan_in + fan_out)).sqrt();
-                
-                // Initialize weights
-                for i in 0..layer.weights.rows() {
-                    for j in 0..layer.weights.cols() {
-                        let weight = rng.random_range(-limit..limit);
-                        layer.weights.set(i, j, weight)?;
-                    }
-                }
+    /// A network with the same layer sizes, activations and execution mode, but fresh
+    /// zero weights and biases; use one of the initializers before training it.
+    /// The activations are cloned as they are, so trainable activation parameters such as
+    /// `PReLU::alpha` keep their current values. `clone` instead copies the weights as well.
+    pub fn clone_architecture(&self) -> NeuralNetworkResult<Self> {
+        let activations = self.layers.iter().map(|layer| layer.activation.clone()).collect();
+        Self::with_activations(self.architecture.clone(), activations, self.concurrent)
+    }
 
-                // Initialize biases to zero
-                for i in 0..layer.biases.rows() {
-                    layer.biases.set(i, 0, 0.0)?;
-                }
+    /// Initialize weights and biases with Xavier/Glorot initialization (uniform)
+    pub fn xavier_initialization(&mut self) -> NeuralNetworkResult<()> {
+        self.initialize_weights(None, Self::xavier_uniform)
+    }
 
-                Ok(())
-            })?;
-        } else {
-            let mut rng = rand::rng();
-            for layer in &mut self.layers {
-                let fan_in = layer.input_size() as f64;
-                let fan_out = layer.output_size() as f64;
-                let limit = (6.0 / (fan_in + fan_out)).sqrt();
-                
-                // Initialize weights
-                for i in 0..layer.weights.rows() {
-                    for j in 0..layer.weights.cols() {
-                        let weight = rng.random_range(-limit..limit);
-                        layer.weights.set(i, j, weight)?;
-                    }
-                }
+    /// Seeded, reproducible variant of `xavier_initialization`
+    pub fn xavier_initialization_seeded(&mut self, seed: u64) -> NeuralNetworkResult<()> {
+        self.initialize_weights(Some(seed), Self::xavier_uniform)
+    }
 
-                // Initialize biases to zero
-                for i in 0..layer.biases.rows() {
-                    layer.biases.set(i, 0, 0.0)?;
-                }
-            }
-        }
+    /// Initialize weights and biases with Xavier/Glorot initialization (normal)
+    pub fn xavier_normal_initialization(&mut self) -> NeuralNetworkResult<()> {
+        self.initialize_weights(None, Self::xavier_normal)
+    }
 
-        Ok(())
+    /// Seeded, reproducible variant of `xavier_normal_initialization`
+    pub fn xavier_normal_initialization_seeded(&mut self, seed: u64) -> NeuralNetworkResult<()> {
+        self.initialize_weights(Some(seed), Self::xavier_normal)
     }
 
-    /// Initialize weights and biases with He initialization (good for ReLU)
+    /// Initialize weights and biases with He initialization (normal, good for ReLU)
     pub fn he_initialization(&mut self) -> NeuralNetworkResult<()> {
-        if self.concurrent {
-            // Use parallel initialization with thread-local RNGs
-            self.layers.par_iter_mut().try_for_each(|layer| -> NeuralNetworkResult<()> {
-                let mut rng = rand::rng(); // Use the new rand::rng() function
-                let fan_in = layer.input_size() as f64;
-                let std_dev = (2.0 / fan_in).sqrt();
-                let normal = Normal::new(0.0, std_dev).map_err(|_| {
-                    NeuralNetworkError::InitializationError("Failed to create normal distribution".to_string())
-                })?;
-                
-                // Initialize weights
-                for i in 0..layer.weights.rows() {
-                    for j in 0..layer.weights.cols() {
-                        let weight = normal.sample(&mut rng);
-                        layer.weights.set(i, j, weight)?;
-                    }
-                }
+        self.initialize_weights(None, Self::he_normal)
+    }
 
-                // Initialize biases to zero
-                for i in 0..layer.biases.rows() {
-                    layer.biases.set(i, 0, 0.0)?;
-                }
+    /// Seeded, reproducible variant of `he_initialization`
+    pub fn he_initialization_seeded(&mut self, seed: u64) -> NeuralNetworkResult<()> {
+        self.initialize_weights(Some(seed), Self::he_normal)
+    }
+
+    /// Initialize weights and biases with He initialization (uniform, good for ReLU)
+    pub fn he_uniform_initialization(&mut self) -> NeuralNetworkResult<()> {
+        self.initialize_weights(None, Self::he_uniform)
+    }
+
+    /// Seeded, reproducible variant of `he_uniform_initialization`
+    pub fn he_uniform_initialization_seeded(&mut self, seed: u64) -> NeuralNetworkResult<()> {
+        self.initialize_weights(Some(seed), Self::he_uniform)
+    }
+
+    /// Initialize weights and biases with LeCun initialization (normal, good for Tanh/Sigmoid)
+    pub fn lecun_initialization(&mut self) -> NeuralNetworkResult<()> {
+        self.initialize_weights(None, Self::lecun_normal)
+    }
+
+    /// Seeded, reproducible variant of `lecun_initialization`
+    pub fn lecun_initialization_seeded(&mut self, seed: u64) -> NeuralNetworkResult<()> {
+        self.initialize_weights(Some(seed), Self::lecun_normal)
+    }
 
-                Ok(())
+    fn xavier_uniform(fan_in: f64, fan_out: f64) -> NeuralNetworkResult<Uniform<f64>> {
+        Self::uniform((6.0 / (fan_in + fan_out)).sqrt())
+    }
+
+    fn xavier_normal(fan_in: f64, fan_out: f64) -> NeuralNetworkResult<Normal<f64>> {
+        Self::normal((2.0 / (fan_in + fan_out)).sqrt())
+    }
+
+    fn he_normal(fan_in: f64, _fan_out: f64) -> NeuralNetworkResult<Normal<f64>> {
+        Self::normal((2.0 / fan_in).sqrt())
+    }
+
+    fn he_uniform(fan_in: f64, _fan_out: f64) -> NeuralNetworkResult<Uniform<f64>> {
+        Self::uniform((6.0 / fan_in).sqrt())
+    }
+
+    fn lecun_normal(fan_in: f64, _fan_out: f64) -> NeuralNetworkResult<Normal<f64>> {
+        Self::normal((1.0 / fan_in).sqrt())
+    }
+
+    /// Fill every layer's weights from the distribution built for its (fan_in, fan_out)
+    /// and reset its biases to zero
+    fn initialize_weights<D, F>(&mut self, seed: Option<u64>, distribution: F) -> NeuralNetworkResult<()>
+    where
+        D: Distribution<f64>,
+        F: Fn(f64, f64) -> NeuralNetworkResult<D> + Sync,
+    {
+        self.initialize_layers(seed, |layer, rng| Self::initialize_layer(layer, &distribution, rng))
+    }
+
+    /// Run `init` on every layer with its own RNG. With a seed, each layer's RNG is seeded from
+    /// the seed and the layer index, so the result is the same in concurrent and sequential mode.
+    fn initialize_layers<F>(&mut self, seed: Option<u64>, init: F) -> NeuralNetworkResult<()>
+    where
+        F: Fn(&mut Layer<f64, A>, &mut dyn RngCore) -> NeuralNetworkResult<()> + Sync,
+    {
+        let layer_rng = |index: usize| -> Box<dyn RngCore> {
+            match seed {
+                Some(seed) => Box::new(Pcg64::seed_from_u64(
+                    seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                )),
+                None => Box::new(rand::rng()),
+            }
+        };
+
+        if self.concurrent {
+            // Use parallel initialization with per-layer RNGs
+            self.layers.par_iter_mut().enumerate().try_for_each(|(index, layer)| {
+                init(layer, &mut *layer_rng(index))
             })?;
         } else {
-            let mut rng = rand::rng();
-            for layer in &mut self.layers {
-                let fan_in = layer.input_size() as f64;
-                let std_dev = (2.0 / fan_in).sqrt();
-                let normal = Normal::new(0.0, std_dev).map_err(|_| {
-                    NeuralNetworkError::InitializationError("Failed to create normal distribution".to_string())
-                })?;
-                
-                // Initialize weights
-                for i in 0..layer.weights.rows() {
-                    for j in 0..layer.weights.cols() {
-                        let weight = normal.sample(&mut rng);
-                        layer.weights.set(i, j, weight)?;
-                    }
-                }
+            for (index, layer) in self.layers.iter_mut().enumerate() {
+                init(layer, &mut *layer_rng(index))?;
+            }
+        }
 
-                // Initialize biases to zero
-                for i in 0..layer.biases.rows() {
-                    layer.biases.set(i, 0, 0.0)?;
-                }
+        Ok(())
+    }
+
+    fn initialize_layer<D, F, R>(layer: &mut Layer<f64, A>, distribution: &F, rng: &mut R) -> NeuralNetworkResult<()>
+    where
+        D: Distribution<f64>,
+        F: Fn(f64, f64) -> NeuralNetworkResult<D>,
+        R: Rng + ?Sized,
+    {
+        let fan_in = layer.input_size() as f64;
+        let fan_out = layer.output_size() as f64;
+        let dist = distribution(fan_in, fan_out)?;
+
+        // Initialize weights
+        for i in 0..layer.weights.rows() {
+            for j in 0..layer.weights.cols() {
+                let weight = dist.sample(rng);
+                layer.weights.set(i, j, weight)?;
             }
         }
 
+        // Initialize biases to zero
+        for i in 0..layer.biases.rows() {
+            layer.biases.set(i, 0, 0.0)?;
+        }
+
         Ok(())
     }
 
+    fn normal(std_dev: f64) -> NeuralNetworkResult<Normal<f64>> {
+        Normal::new(0.0, std_dev).map_err(|_| {
+            NeuralNetworkError::InitializationError("Failed to create normal distribution".to_string())
+        })
+    }
+
+    fn uniform(limit: f64) -> NeuralNetworkResult<Uniform<f64>> {
+        Uniform::new(-limit, limit).map_err(|_| {
+            NeuralNetworkError::InitializationError("Failed to create uniform distribution".to_string())
+        })
+    }
+
     /// Random initialization with given range
     pub fn random_initialization(&mut self, min: f64, max: f64) -> NeuralNetworkResult<()> {
-        if self.concurrent {
-            // Use parallel initialization with thread-local RNGs
-            self.layers.par_iter_mut().try_for_each(|layer| -> NeuralNetworkResult<()> {
-                let mut rng = rand::rng(); // Use the new rand::rng() function
-                
-                // Initialize weights
-                for i in 0..layer.weights.rows() {
-                    for j in 0..layer.weights.cols() {
-                        let weight = rng.random_range(min..max);
-                        layer.weights.set(i, j, weight)?;
-                    }
-                }
-
-                // Initialize biases
-                for i in 0..layer.biases.rows() {
-                    let bias = rng.random_range(min..max);
-                    layer.biases.set(i, 0, bias)?;
-                }
+        self.initialize_layers(None, |layer, rng| Self::randomize_layer(layer, min, max, rng))
+    }
 
-                Ok(())
-            })?;
-        } else {
-            let mut rng = rand::rng();
-            for layer in &mut self.layers {
-                // Initialize weights
-                for i in 0..layer.weights.rows() {
-                    for j in 0..layer.weights.cols() {
-                        let weight = rng.random_range(min..max);
-                        layer.weights.set(i, j, weight)?;
-                    }
-                }
+    /// Seeded, reproducible variant of `random_initialization`
+    pub fn random_initialization_seeded(&mut self, min: f64, max: f64, seed: u64) -> NeuralNetworkResult<()> {
+        self.initialize_layers(Some(seed), |layer, rng| Self::randomize_layer(layer, min, max, rng))
+    }
 
-                // Initialize biases
-                for i in 0..layer.biases.rows() {
-                    let bias = rng.random_range(min..max);
-                    layer.biases.set(i, 0, bias)?;
-                }
+    fn randomize_layer(layer: &mut Layer<f64, A>, min: f64, max: f64, rng: &mut dyn RngCore) -> NeuralNetworkResult<()> {
+        // Initialize weights
+        for i in 0..layer.weights.rows() {
+            for j in 0..layer.weights.cols() {
+                let weight = rng.random_range(min..max);
+                layer.weights.set(i, j, weight)?;
             }
         }
 
+        // Initialize biases
+        for i in 0..layer.biases.rows() {
+            let bias = rng.random_range(min..max);
+            layer.biases.set(i, 0, bias)?;
+        }
+
         Ok(())
     }
 
-    /// Forward propagation through the network
+    /// Forward propagation through the network.
+    /// Accepts an `input_size x 1` column or a `1 x input_size` row vector.
     pub fn forward(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
-        if input.rows() != self.architecture[0] {
-            return Err(NeuralNetworkError::InvalidInputSize {
-                expected: self.architecture[0],
-                actual: input.rows(),
-            });
-        }
+        let mut current_output = self.input_column(input)?;
 
-        if input.cols() != 1 {
-            return Err(NeuralNetworkError::InvalidInputSize {
-                expected: 1,
-                actual: input.cols(),
-            });
+        for layer in &self.layers {
+            // Linear transformation: W * x + b, then the activation applied in place
+            current_output = Self::linear_forward(layer, &current_output)?;
+            layer.activation.activate_inplace(&mut current_output)?;
         }
 
-        let mut current_output = input.clone();
+        Ok(current_output)
+    }
+
+    /// Forward propagation with intermediate outputs (useful for training).
+    /// Accepts the same shapes as `forward`; the first entry is always the input as a column.
+    pub fn forward_with_intermediates(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Vec<Matrix<f64>>> {
+        let (activations, _) = self.forward_with_pre_activations(input)?;
+        Ok(activations)
+    }
+
+    /// Forward propagation returning both the post-activation outputs (input first)
+    /// and the pre-activation values z = W * x + b of every layer.
+    /// Activation derivatives are defined in terms of z, so backpropagation needs these.
+    pub fn forward_with_pre_activations(
+        &self,
+        input: &Matrix<f64>,
+    ) -> NeuralNetworkResult<ForwardPass> {
+        let mut activations = Vec::with_capacity(self.layers.len() + 1);
+        let mut pre_activations = Vec::with_capacity(self.layers.len());
+        let mut current_output = self.input_column(input)?;
+        activations.push(current_output.clone());
 
         for layer in &self.layers {
             // Linear transformation: W * x + b
-            let linear_output = layer.weights.matrix_multiply(&current_output)?;
-            let linear_with_bias = (linear_output + layer.biases.clone())?;
+            let linear_with_bias = Self::linear_forward(layer, &current_output)?;
             
             // Apply activation function
             current_output = layer.activation.activate(&linear_with_bias)?;
+            pre_activations.push(linear_with_bias);
+            activations.push(current_output.clone());
         }
 
-        Ok(current_output)
+        Ok((activations, pre_activations))
     }
 
-    /// Forward propagation with intermediate outputs (useful for training)
-    pub fn forward_with_intermediates(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Vec<Matrix<f64>>> {
-        if input.rows() != self.architecture[0] {
-            return Err(NeuralNetworkError::InvalidInputSize {
-                expected: self.architecture[0],
-                actual: input.rows(),
-            });
+    /// Validate the input shape and return it as an `input_size x 1` column
+    fn input_column(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        let size = self.architecture[0];
+        match input.dimensions() {
+            (rows, 1) if rows == size => Ok(input.clone()),
+            (1, cols) if cols == size => Ok(input.transpose()?),
+            (rows, cols) if rows == size => Err(NeuralNetworkError::InvalidInputSize {
+                expected: 1,
+                actual: cols,
+            }),
+            (1, cols) => Err(NeuralNetworkError::InvalidInputSize {
+                expected: size,
+                actual: cols,
+            }),
+            (rows, _) => Err(NeuralNetworkError::InvalidInputSize {
+                expected: size,
+                actual: rows,
+            }),
         }
+    }
+
+    /// Predicted class for one input: the argmax of the output column,
+    /// or the output thresholded at 0.5 for single-output networks (as in `accuracy`)
+    pub fn predict(&self, input: &Matrix<f64>) -> NeuralNetworkResult<usize> {
+        Self::predicted_class(&self.predict_proba(input)?)
+    }
+
+    /// The network output for one input, checked to be a single column of class scores
+    pub fn predict_proba(&self, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        let output = self.forward(input)?;
+        if output.cols() != 1 {
+            return Err(NeuralNetworkError::ForwardPropagationError(format!(
+                "Expected a column vector output, got {}x{}", output.rows(), output.cols()
+            )));
+        }
+        Ok(output)
+    }
 
-        if input.cols() != 1 {
+    /// Fraction of samples whose predicted class matches the target class.
+    /// Classes are the argmax of each output column; single-output networks threshold at 0.5.
+    pub fn accuracy(&self, inputs: &[Matrix<f64>], targets: &[Matrix<f64>]) -> NeuralNetworkResult<f64> {
+        if inputs.len() != targets.len() {
             return Err(NeuralNetworkError::InvalidInputSize {
-                expected: 1,
-                actual: input.cols(),
+                expected: inputs.len(),
+                actual: targets.len(),
             });
         }
+        if inputs.is_empty() {
+            return Ok(0.0);
+        }
 
-        let mut outputs = Vec::new();
-        let mut current_output = input.clone();
-        outputs.push(current_output.clone());
+        let mut correct = 0;
+        for (input, target) in inputs.iter().zip(targets.iter()) {
+            let prediction = self.forward(input)?;
+            if Self::predicted_class(&prediction)? == Self::predicted_class(target)? {
+                correct += 1;
+            }
+        }
 
-        for layer in &self.layers {
-            // Linear transformation: W * x + b
-            let linear_output = layer.weights.matrix_multiply(&current_output)?;
-            let linear_with_bias = (linear_output + layer.biases.clone())?;
-            
-            // Apply activation function
-            current_output = layer.activation.activate(&linear_with_bias)?;
-            outputs.push(current_output.clone());
+        Ok(correct as f64 / inputs.len() as f64)
+    }
+
+    /// Weight and bias gradients of `cost`, averaged over the samples, as one SGD batch on them
+    /// would apply them. The network itself is left untouched.
+    pub fn compute_gradients<C: CostFunction>(
+        &self,
+        inputs: &[Matrix<f64>],
+        targets: &[Matrix<f64>],
+        cost: &C,
+    ) -> NeuralNetworkResult<Gradients> {
+        SGD::new(cost.clone()).compute_gradients(self, inputs, targets)
+    }
+
+    fn predicted_class(output: &Matrix<f64>) -> NeuralNetworkResult<usize> {
+        let values = output.col(0)?;
+        if values.len() == 1 {
+            return Ok(usize::from(values[0] >= 0.5));
+        }
+
+        let mut best = 0;
+        for (i, &value) in values.iter().enumerate() {
+            if value > values[best] {
+                best = i;
+            }
         }
+        Ok(best)
+    }
+
+    /// Compute W * x + b for one layer.
+    /// With the `tensor-simd` feature, large weight matrices use the tensor crate's AVX2 matmul.
+    fn linear_forward(layer: &Layer<f64, A>, input: &Matrix<f64>) -> NeuralNetworkResult<Matrix<f64>> {
+        #[cfg(feature = "tensor-simd")]
+        let mut linear = if layer.weights.rows() * layer.weights.cols() >= crate::interop::SIMD_MATMUL_THRESHOLD {
+            crate::interop::simd_matrix_multiply(&layer.weights, input)?
+        } else {
+            (&layer.weights * input)?
+        };
+        #[cfg(not(feature = "tensor-simd"))]
+        let mut linear = (&layer.weights * input)?;
 
-        Ok(outputs)
+        linear.add_assign_broadcast(&layer.biases)?;
+        Ok(linear)
     }
 
     /// Get network architecture
@@ -369,18 +511,58 @@ where
             layer.weights.rows() * layer.weights.cols() + layer.biases.rows()
         }).sum()
     }
+
+    /// Flatten all parameters into one vector: each layer's weights (row-major), then its biases
+    pub fn parameters(&self) -> Vec<f64> {
+        let mut params = Vec::with_capacity(self.parameter_count());
+        for layer in &self.layers {
+            for matrix in [&layer.weights, &layer.biases] {
+                let (rows, cols) = matrix.dimensions();
+                params.extend((0..rows * cols).map(|i| *matrix.get_flat(i).unwrap()));
+            }
+        }
+        params
+    }
+
+    /// Load parameters in the order produced by `parameters`
+    pub fn set_parameters(&mut self, params: &[f64]) -> NeuralNetworkResult<()> {
+        let expected = self.parameter_count();
+        if params.len() != expected {
+            return Err(NeuralNetworkError::InvalidParameterCount {
+                expected,
+                actual: params.len(),
+            });
+        }
+
+        let mut values = params.iter().copied();
+        for layer in &mut self.layers {
+            for matrix in [&mut layer.weights, &mut layer.biases] {
+                let (rows, cols) = matrix.dimensions();
+                for (i, value) in (0..rows * cols).zip(&mut values) {
+                    matrix.set_flat(i, value)?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 
 /// Macro for creating neural networks with a simple syntax
 #[macro_export]
 macro_rules! nn {
+    // nn![2, 4 => ReLU, 3 => Sigmoid]: the input size, then each layer's size and activation
+    ($input:expr $(, $size:expr => $activation:expr)+ $(,)?) => {{
+        let architecture = vec![$input $(, $size)+];
+        let activations = vec![$($crate::Activation::from($activation)),+];
+        $crate::NeuralNetwork::with_activations(architecture, activations, true)
+    }};
     ($($size:expr),+ ; $activation:expr) => {{
         let architecture = vec![$($size),+];
-        NeuralNetwork::new(architecture, $activation, true)
+        $crate::NeuralNetwork::new(architecture, $activation, true)
     }};
     ($($size:expr),+) => {{
         let architecture = vec![$($size),+];
-        NeuralNetwork::new(architecture, crate::activations::Sigmoid, true)
+        $crate::NeuralNetwork::new(architecture, $crate::Sigmoid, true)
     }};
 }
\ No newline at end of file
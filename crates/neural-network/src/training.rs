@@ -1,22 +1,34 @@
 use matrix::Matrix;
-use crate::nn::{NeuralNetwork, Layer};
+use crate::nn::NeuralNetwork;
 use crate::activation::ActivationFunction;
 use crate::cost::CostFunction;
 use crate::error::{NeuralNetworkError, NeuralNetworkResult};
 use std::time::{Duration, Instant};
-use rayon::prelude::*;
+
+/// Per-layer (weight, bias) gradients, in layer order
+pub type Gradients = (Vec<Matrix<f64>>, Vec<Matrix<f64>>);
 
 /// Training configuration
 #[derive(Debug, Clone)]
 pub struct TrainingConfig {
     pub learning_rate: f64,
     pub epochs: usize,
+    /// Samples per gradient step; must be at least 1. A batch larger than the
+    /// training set is clamped, so the whole set becomes a single batch.
     pub batch_size: usize,
+    /// Batches whose gradients are summed before each weight update; must be at least 1.
+    /// The update averages over every sample of those batches, so `batch_size = 16` with
+    /// two accumulation steps updates like `batch_size = 32`.
+    pub accumulation_steps: usize,
     pub validation_split: f64,
     pub early_stopping_patience: Option<usize>,
     pub min_improvement: f64,
+    /// Put back the weights of the epoch with the lowest validation loss once training ends.
+    /// Has no effect without a validation split.
+    pub restore_best: bool,
     pub verbose: bool,
     pub log_interval: usize,
+    pub track_accuracy: bool,
 }
 
 impl Default for TrainingConfig {
@@ -25,11 +37,14 @@ impl Default for TrainingConfig {
             learning_rate: 0.001,
             epochs: 1000,
             batch_size: 32,
+            accumulation_steps: 1,
             validation_split: 0.2,
             early_stopping_patience: Some(10),
             min_improvement: 1e-6,
+            restore_best: false,
             verbose: true,
             log_interval: 100,
+            track_accuracy: false,
         }
     }
 }
@@ -40,6 +55,8 @@ pub struct TrainingMetrics {
     pub epoch: usize,
     pub train_loss: f64,
     pub validation_loss: Option<f64>,
+    pub train_accuracy: Option<f64>,
+    pub validation_accuracy: Option<f64>,
     pub epoch_duration: Duration,
     pub total_duration: Duration,
 }
@@ -53,6 +70,12 @@ pub struct TrainingHistory {
     pub stopped_early: bool,
 }
 
+impl Default for TrainingHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TrainingHistory {
     pub fn new() -> Self {
         Self {
@@ -73,6 +96,21 @@ impl TrainingHistory {
         self.metrics.push(metric);
     }
 
+    /// Wall-clock time of the whole run, as recorded with the last epoch
+    pub fn total_time(&self) -> Duration {
+        self.metrics.last().map_or(Duration::ZERO, |metric| metric.total_duration)
+    }
+
+    /// Mean epoch duration (zero if no epoch was recorded)
+    pub fn average_epoch_time(&self) -> Duration {
+        let total: Duration = self.metrics.iter().map(|metric| metric.epoch_duration).sum();
+        total / self.metrics.len().max(1) as u32
+    }
+
+    pub fn fastest_epoch(&self) -> Option<&TrainingMetrics> {
+        self.metrics.iter().min_by_key(|metric| metric.epoch_duration)
+    }
+
     pub fn print_summary(&self) {
         println!("\n╔══════════════════════════════════════════════════════════════════════════════╗");
         println!("║                                TRAINING SUMMARY                                 ║");
@@ -85,10 +123,27 @@ impl TrainingHistory {
                 last_metric.total_duration.as_millis() as f64 / last_metric.epoch as f64
             );
             
-            println!("║ Final Train Loss: {:>12.6} │ Final Val Loss: {:>12.6} │              ║", 
-                last_metric.train_loss,
-                last_metric.validation_loss.unwrap_or(0.0)
-            );
+            match last_metric.validation_loss {
+                Some(validation_loss) => println!("║ Final Train Loss: {:>12.6} │ Final Val Loss: {:>12.6} │              ║", 
+                    last_metric.train_loss,
+                    validation_loss
+                ),
+                None => println!("║ Final Train Loss: {:>12.6} │ No validation split: training loss drove the run  ║", 
+                    last_metric.train_loss
+                ),
+            }
+
+            if let Some(train_accuracy) = last_metric.train_accuracy {
+                match last_metric.validation_accuracy {
+                    Some(validation_accuracy) => println!("║ Final Train Acc: {:>11.2}% │ Final Val Acc: {:>11.2}% │              ║", 
+                        train_accuracy * 100.0,
+                        validation_accuracy * 100.0
+                    ),
+                    None => println!("║ Final Train Acc: {:>11.2}% │                                                 ║", 
+                        train_accuracy * 100.0
+                    ),
+                }
+            }
         }
         
         if let Some(best_loss) = self.best_validation_loss {
@@ -145,12 +200,44 @@ where
         targets: &[Matrix<f64>],
         config: &TrainingConfig,
     ) -> NeuralNetworkResult<TrainingHistory> {
+        self.train_with_callback(network, inputs, targets, config, |_, _| true)
+    }
+
+    fn name(&self) -> &'static str {
+        "SGD"
+    }
+}
+
+impl<C: CostFunction> SGD<C> {
+    /// Train like `train`, calling `on_epoch` after every epoch has been recorded.
+    /// Returning `false` from the callback stops training after that epoch.
+    pub fn train_with_callback<A>(
+        &mut self,
+        network: &mut NeuralNetwork<f64, A>,
+        inputs: &[Matrix<f64>],
+        targets: &[Matrix<f64>],
+        config: &TrainingConfig,
+        mut on_epoch: impl FnMut(&TrainingMetrics, &NeuralNetwork<f64, A>) -> bool,
+    ) -> NeuralNetworkResult<TrainingHistory>
+    where
+        A: ActivationFunction<f64>,
+    {
         if inputs.len() != targets.len() {
             return Err(NeuralNetworkError::InvalidInputSize {
                 expected: inputs.len(),
                 actual: targets.len(),
             });
         }
+        if config.batch_size == 0 {
+            return Err(NeuralNetworkError::InvalidTrainingConfig(
+                "batch_size must be at least 1".to_string()
+            ));
+        }
+        if config.accumulation_steps == 0 {
+            return Err(NeuralNetworkError::InvalidTrainingConfig(
+                "accumulation_steps must be at least 1".to_string()
+            ));
+        }
 
         let mut history = TrainingHistory::new();
         let start_time = Instant::now();
@@ -159,8 +246,21 @@ where
         let split_idx = ((1.0 - config.validation_split) * inputs.len() as f64) as usize;
         let (train_inputs, val_inputs) = inputs.split_at(split_idx);
         let (train_targets, val_targets) = targets.split_at(split_idx);
+        if train_inputs.is_empty() {
+            return Err(NeuralNetworkError::EmptyDataset);
+        }
 
+        // Without a validation set there is nothing to stop early on
+        let early_stopping_patience = if val_inputs.is_empty() {
+            if config.verbose && config.early_stopping_patience.is_some() {
+                println!("No validation split: early stopping disabled, training loss drives the run");
+            }
+            None
+        } else {
+            config.early_stopping_patience
+        };
         let mut patience_counter = 0;
+        let mut best_parameters = None;
 
         for epoch in 1..=config.epochs {
             let epoch_start = Instant::now();
@@ -175,6 +275,18 @@ where
                 None
             };
 
+            // Accuracy (classification only)
+            let (train_accuracy, validation_accuracy) = if config.track_accuracy {
+                let validation_accuracy = if !val_inputs.is_empty() {
+                    Some(network.accuracy(val_inputs, val_targets)?)
+                } else {
+                    None
+                };
+                (Some(network.accuracy(train_inputs, train_targets)?), validation_accuracy)
+            } else {
+                (None, None)
+            };
+
             let epoch_duration = epoch_start.elapsed();
             let total_duration = start_time.elapsed();
 
@@ -182,12 +294,14 @@ where
                 epoch,
                 train_loss,
                 validation_loss,
+                train_accuracy,
+                validation_accuracy,
                 epoch_duration,
                 total_duration,
             };
 
             // Check for early stopping
-            if let (Some(val_loss), Some(patience)) = (validation_loss, config.early_stopping_patience) {
+            let stop_early = if let (Some(val_loss), Some(patience)) = (validation_loss, early_stopping_patience) {
                 if let Some(best_loss) = history.best_validation_loss {
                     if best_loss - val_loss < config.min_improvement {
                         patience_counter += 1;
@@ -195,35 +309,45 @@ where
                         patience_counter = 0;
                     }
                 }
+                patience_counter >= patience
+            } else {
+                false
+            };
 
-                if patience_counter >= patience {
-                    if config.verbose {
-                        println!("Early stopping triggered at epoch {}", epoch);
-                    }
-                    history.add_metric(metric);
-                    history.stopped_early = true;
-                    break;
-                }
+            history.add_metric(metric.clone());
+            if config.restore_best && history.best_epoch == epoch {
+                best_parameters = Some(network.parameters());
             }
 
-            history.add_metric(metric.clone());
+            if stop_early {
+                if config.verbose {
+                    println!("Early stopping triggered at epoch {}", epoch);
+                }
+                history.stopped_early = true;
+                on_epoch(&metric, network);
+                break;
+            }
 
             // Logging
             if config.verbose && (epoch % config.log_interval == 0 || epoch == 1) {
-                self.log_progress(&metric);
+                self.log_progress(&metric, config);
+            }
+
+            if !on_epoch(&metric, network) {
+                break;
             }
         }
 
+        if let Some(parameters) = best_parameters {
+            network.set_parameters(&parameters)?;
+        }
+
         if config.verbose {
             history.print_summary();
         }
 
         Ok(history)
     }
-
-    fn name(&self) -> &'static str {
-        "SGD"
-    }
 }
 
 impl<C: CostFunction> SGD<C> {
@@ -236,45 +360,92 @@ impl<C: CostFunction> SGD<C> {
     ) -> NeuralNetworkResult<f64> {
         let mut total_loss = 0.0;
         let mut batches_processed = 0;
+        let (mut weight_gradients, mut bias_gradients) = Self::zero_gradients(network)?;
+        let mut pending_samples = 0;
 
-        // Create batches
+        // Create batches; gradients are applied once every `accumulation_steps` batches
         for batch_start in (0..inputs.len()).step_by(config.batch_size) {
             let batch_end = (batch_start + config.batch_size).min(inputs.len());
             let batch_inputs = &inputs[batch_start..batch_end];
             let batch_targets = &targets[batch_start..batch_end];
 
-            let batch_loss = self.train_batch(network, batch_inputs, batch_targets, config)?;
+            let batch_loss = self.train_batch(network, batch_inputs, batch_targets, &mut weight_gradients, &mut bias_gradients)?;
             total_loss += batch_loss;
             batches_processed += 1;
+            pending_samples += batch_inputs.len();
+
+            if batches_processed % config.accumulation_steps == 0 {
+                self.apply_gradients(network, &weight_gradients, &bias_gradients, config.learning_rate, pending_samples)?;
+                (weight_gradients, bias_gradients) = Self::zero_gradients(network)?;
+                pending_samples = 0;
+            }
+        }
+
+        // Apply what is left of an incomplete accumulation at the end of the epoch
+        if pending_samples > 0 {
+            self.apply_gradients(network, &weight_gradients, &bias_gradients, config.learning_rate, pending_samples)?;
         }
 
         Ok(total_loss / batches_processed as f64)
     }
 
-    fn train_batch(
-        &self,
-        network: &mut NeuralNetwork<f64, impl ActivationFunction<f64>>,
-        batch_inputs: &[Matrix<f64>],
-        batch_targets: &[Matrix<f64>],
-        config: &TrainingConfig,
-    ) -> NeuralNetworkResult<f64> {
-        let mut total_loss = 0.0;
+    fn zero_gradients(
+        network: &NeuralNetwork<f64, impl ActivationFunction<f64>>,
+    ) -> NeuralNetworkResult<Gradients> {
         let mut weight_gradients = Vec::new();
         let mut bias_gradients = Vec::new();
 
-        // Initialize gradient accumulators
         for layer_idx in 0..network.num_layers() {
             let layer = network.get_layer(layer_idx)?;
-            let weight_grad = Matrix::zeros(layer.weights.rows(), layer.weights.cols())?;
-            let bias_grad = Matrix::zeros(layer.biases.rows(), layer.biases.cols())?;
-            weight_gradients.push(weight_grad);
-            bias_gradients.push(bias_grad);
+            weight_gradients.push(Matrix::zeros(layer.weights.rows(), layer.weights.cols())?);
+            bias_gradients.push(Matrix::zeros(layer.biases.rows(), layer.biases.cols())?);
+        }
+
+        Ok((weight_gradients, bias_gradients))
+    }
+
+    /// Gradients of the average loss over the samples, computed like a training batch but not applied
+    pub(crate) fn compute_gradients(
+        &self,
+        network: &NeuralNetwork<f64, impl ActivationFunction<f64>>,
+        inputs: &[Matrix<f64>],
+        targets: &[Matrix<f64>],
+    ) -> NeuralNetworkResult<Gradients> {
+        if inputs.len() != targets.len() {
+            return Err(NeuralNetworkError::InvalidInputSize {
+                expected: inputs.len(),
+                actual: targets.len(),
+            });
+        }
+        if inputs.is_empty() {
+            return Err(NeuralNetworkError::EmptyDataset);
         }
 
+        let (mut weight_gradients, mut bias_gradients) = Self::zero_gradients(network)?;
+        self.train_batch(network, inputs, targets, &mut weight_gradients, &mut bias_gradients)?;
+
+        let sample_count = inputs.len() as f64;
+        let average = |gradients: Vec<Matrix<f64>>| -> NeuralNetworkResult<Vec<Matrix<f64>>> {
+            gradients.into_iter().map(|gradient| Ok((gradient / sample_count)?)).collect()
+        };
+        Ok((average(weight_gradients)?, average(bias_gradients)?))
+    }
+
+    /// Add the batch's gradients to the accumulators and return its average loss
+    fn train_batch(
+        &self,
+        network: &NeuralNetwork<f64, impl ActivationFunction<f64>>,
+        batch_inputs: &[Matrix<f64>],
+        batch_targets: &[Matrix<f64>],
+        weight_gradients: &mut [Matrix<f64>],
+        bias_gradients: &mut [Matrix<f64>],
+    ) -> NeuralNetworkResult<f64> {
+        let mut total_loss = 0.0;
+
         // Process each sample in the batch
         for (input, target) in batch_inputs.iter().zip(batch_targets.iter()) {
             // Forward propagation
-            let activations = network.forward_with_intermediates(input)?;
+            let (activations, pre_activations) = network.forward_with_pre_activations(input)?;
             let prediction = activations.last().unwrap();
 
             // Calculate loss
@@ -282,12 +453,9 @@ impl<C: CostFunction> SGD<C> {
             total_loss += loss;
 
             // Backpropagation
-            self.backpropagate(network, &activations, target, &mut weight_gradients, &mut bias_gradients)?;
+            self.backpropagate(network, &activations, &pre_activations, target, weight_gradients, bias_gradients)?;
         }
 
-        // Apply gradients
-        self.apply_gradients(network, &weight_gradients, &bias_gradients, config.learning_rate, batch_inputs.len())?;
-
         Ok(total_loss / batch_inputs.len() as f64)
     }
 
@@ -295,6 +463,7 @@ impl<C: CostFunction> SGD<C> {
         &self,
         network: &NeuralNetwork<f64, impl ActivationFunction<f64>>,
         activations: &[Matrix<f64>],
+        pre_activations: &[Matrix<f64>],
         target: &Matrix<f64>,
         weight_gradients: &mut [Matrix<f64>],
         bias_gradients: &mut [Matrix<f64>],
@@ -306,10 +475,10 @@ impl<C: CostFunction> SGD<C> {
         for layer_idx in (0..num_layers).rev() {
             let layer = network.get_layer(layer_idx)?;
             let layer_input = &activations[layer_idx];
-            let layer_output = &activations[layer_idx + 1];
+            let layer_pre_activation = &pre_activations[layer_idx];
 
-            // Calculate derivative of activation function
-            let activation_derivative = layer.activation.derivative(layer_output)?;
+            // Calculate derivative of activation function at z, not at a(z)
+            let activation_derivative = layer.activation.derivative(layer_pre_activation)?;
 
             // Element-wise multiplication of delta and activation derivative
             for i in 0..delta.rows() {
@@ -389,30 +558,56 @@ impl<C: CostFunction> SGD<C> {
         Ok(())
     }
 
-    fn validate(
+    pub(crate) fn validate(
         &self,
         network: &NeuralNetwork<f64, impl ActivationFunction<f64>>,
         inputs: &[Matrix<f64>],
         targets: &[Matrix<f64>],
     ) -> NeuralNetworkResult<f64> {
-        let mut total_loss = 0.0;
+        if inputs.is_empty() {
+            return Err(NeuralNetworkError::EmptyDataset);
+        }
+        let total_loss: f64 = self.validate_detailed(network, inputs, targets)?.iter().sum();
+        Ok(total_loss / inputs.len() as f64)
+    }
 
-        for (input, target) in inputs.iter().zip(targets.iter()) {
-            let prediction = network.forward(input)?;
-            let loss = self.cost_function.cost(&prediction, target)?;
-            total_loss += loss;
+    /// Loss of every sample, in input order, for finding the samples the network struggles with
+    pub fn validate_detailed(
+        &self,
+        network: &NeuralNetwork<f64, impl ActivationFunction<f64>>,
+        inputs: &[Matrix<f64>],
+        targets: &[Matrix<f64>],
+    ) -> NeuralNetworkResult<Vec<f64>> {
+        if inputs.len() != targets.len() {
+            return Err(NeuralNetworkError::InvalidInputSize {
+                expected: inputs.len(),
+                actual: targets.len(),
+            });
         }
 
-        Ok(total_loss / inputs.len() as f64)
+        inputs.iter().zip(targets.iter())
+            .map(|(input, target)| {
+                let prediction = network.forward(input)?;
+                self.cost_function.cost(&prediction, target)
+            })
+            .collect()
     }
 
-    fn log_progress(&self, metric: &TrainingMetrics) {
-        println!("Epoch {:>4}/{} | Loss: {:>10.6} | Val Loss: {:>10.6} | Time: {:>6.2}ms",
+    fn log_progress(&self, metric: &TrainingMetrics, config: &TrainingConfig) {
+        println!("{}", self.format_progress(metric, config.epochs, config.learning_rate));
+    }
+
+    pub(crate) fn format_progress(&self, metric: &TrainingMetrics, total_epochs: usize, learning_rate: f64) -> String {
+        let validation = metric.validation_loss
+            .map(|loss| format!(" | Val Loss: {:>10.6}", loss))
+            .unwrap_or_default();
+        format!("Epoch {:>4}/{} | Loss: {:>10.6}{} | LR: {:.2e} | Time: {:>6.2}ms",
             metric.epoch,
-            "?", // We don't have total epochs here
+            total_epochs,
             metric.train_loss,
-            metric.validation_loss.unwrap_or(0.0),
+            validation,
+            learning_rate,
             metric.epoch_duration.as_millis()
-        );
+        )
     }
-}
\ No newline at end of file
+}
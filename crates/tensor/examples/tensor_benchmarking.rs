@@ -1,10 +1,8 @@
 use std::time::Instant;
 use std::fmt;
-use std::iter;
 
 // Assuming these are your existing modules
 use tensor::tensor::Tensor;
-use tensor::error::TensorResult;
 use tensor::ExecutionMode;
 
 
@@ -27,9 +25,9 @@ pub struct TensorBenchmark;
 impl TensorBenchmark {
     /// Run comprehensive benchmarks on tensor operations
     pub fn run_full_benchmark() {
-        println!("{}", iter::repeat("=").take(80).collect::<String>());
+        println!("{}", "=".repeat(80));
         println!("TENSOR OPERATIONS COMPREHENSIVE BENCHMARK");
-        println!("{}", iter::repeat("=").take(80).collect::<String>());
+        println!("{}", "=".repeat(80));
         
         Self::benchmark_element_wise_operations();
         Self::benchmark_matrix_vector_multiplication();
@@ -40,7 +38,7 @@ impl TensorBenchmark {
     /// Benchmark element-wise operations (addition, subtraction, Hadamard product)
     fn benchmark_element_wise_operations() {
         println!("\n🧮 ELEMENT-WISE OPERATIONS BENCHMARK");
-        println!("{}", iter::repeat("-").take(50).collect::<String>());
+        println!("{}", "-".repeat(50));
         
         let sizes = vec![
             (100, 100),      // Small matrices
@@ -52,7 +50,7 @@ impl TensorBenchmark {
         for (rows, cols) in sizes {
             println!("\nMatrix size: {}x{}", rows, cols);
             println!("{:<15} | {:>10} | {:>15}", "Operation", "Time (ms)", "Elements/sec");
-            println!("{}", iter::repeat("-").take(45).collect::<String>());
+            println!("{}", "-".repeat(45));
 
             let a = Tensor::random(&[rows, cols], 42);
             let b = Tensor::random(&[rows, cols], 123);
@@ -103,7 +101,7 @@ impl TensorBenchmark {
     /// Benchmark matrix-vector multiplication across all execution modes
     fn benchmark_matrix_vector_multiplication() {
         println!("\n🚀 MATRIX-VECTOR MULTIPLICATION BENCHMARK");
-        println!("{}", iter::repeat("-").take(60).collect::<String>());
+        println!("{}", "-".repeat(60));
         
         let matrix_sizes = vec![
             (512, 512),
@@ -208,7 +206,7 @@ impl TensorBenchmark {
 
     /// Benchmark a tensor operation across all execution modes
     fn benchmark_matrix_operations(a: &Tensor, b: &Tensor) -> Vec<BenchmarkResult> {
-        let modes = vec![
+        let modes = [
             ExecutionMode::Sequential,
             ExecutionMode::Parallel,
             ExecutionMode::SIMD,
@@ -292,11 +290,10 @@ impl TensorBenchmark {
                     
                     if reference_result.is_none() {
                         reference_result = Some(result);
-                    } else if let Some(ref reference) = reference_result {
-                        if result != *reference {
-                            println!("❌ {} produces different result!", mode);
-                            all_correct = false;
-                        }
+                    } else if let Some(ref reference) = reference_result
+                        && result != *reference {
+                        println!("❌ {} produces different result!", mode);
+                        all_correct = false;
                     }
                 }
                 Err(e) => {
@@ -321,11 +318,10 @@ impl TensorBenchmark {
                     
                     if reference_result.is_none() {
                         reference_result = Some(result);
-                    } else if let Some(ref reference) = reference_result {
-                        if result != *reference {
-                            println!("❌ {} produces different result!", mode);
-                            all_correct = false;
-                        }
+                    } else if let Some(ref reference) = reference_result
+                        && result != *reference {
+                        println!("❌ {} produces different result!", mode);
+                        all_correct = false;
                     }
                 }
                 Err(e) => {
@@ -0,0 +1,41 @@
+use std::fmt::{Debug, Display};
+use num_traits::Float;
+use rayon::prelude::*;
+use crate::simd::SimdProcessor;
+use crate::PARALLEL_THRESHOLD;
+
+// Scalar types a `TensorGeneric` can hold. The slice kernels default to plain loops (with rayon
+// past PARALLEL_THRESHOLD); f32 overrides them with the AVX2 paths of `SimdProcessor`.
+pub trait Element: Float + Debug + Display + Send + Sync + 'static {
+    fn add_slice(a: &[Self], b: &[Self], out: &mut [Self]) {
+        zip_into(a, b, out, |x, y| x + y);
+    }
+
+    fn mul_slice(a: &[Self], b: &[Self], out: &mut [Self]) {
+        zip_into(a, b, out, |x, y| x * y);
+    }
+}
+
+impl Element for f32 {
+    fn add_slice(a: &[f32], b: &[f32], out: &mut [f32]) {
+        SimdProcessor::new().add_slice(a, b, out);
+    }
+
+    fn mul_slice(a: &[f32], b: &[f32], out: &mut [f32]) {
+        SimdProcessor::new().mul_slice(a, b, out);
+    }
+}
+
+impl Element for f64 {}
+
+fn zip_into<T: Element>(a: &[T], b: &[T], out: &mut [T], f: impl Fn(T, T) -> T + Sync) {
+    if out.len() >= PARALLEL_THRESHOLD {
+        out.par_iter_mut()
+            .zip(a.par_iter().zip(b.par_iter()))
+            .for_each(|(r, (&x, &y))| *r = f(x, y));
+    } else {
+        for (r, (&x, &y)) in out.iter_mut().zip(a.iter().zip(b)) {
+            *r = f(x, y);
+        }
+    }
+}
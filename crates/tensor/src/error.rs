@@ -7,6 +7,7 @@ pub enum TensorError {
     IndexOutOfBounds(String),
     InvalidOperation(String),
     MatrixMultiplicationError(String),
+    IoError(String),
 }
 
 impl fmt::Display for TensorError {
@@ -17,6 +18,7 @@ impl fmt::Display for TensorError {
             TensorError::IndexOutOfBounds(msg) => write!(f, "Index out of bounds: {}", msg),
             TensorError::InvalidOperation(msg) => write!(f, "Invalid operation: {}", msg),
             TensorError::MatrixMultiplicationError(msg) => write!(f, "Matrix multiplication error: {}", msg),
+            TensorError::IoError(msg) => write!(f, "I/O error: {}", msg),
         }
     }
 }
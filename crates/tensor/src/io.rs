@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::Path;
+use crate::tensor::Tensor;
+use crate::error::{TensorError, TensorResult};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+const NPY_DESCR: &str = "<f4";
+
+impl Tensor {
+    /// Writes the tensor as a NumPy `.npy` v1.0 file with dtype `<f4`
+    pub fn save_npy(&self, path: &Path) -> TensorResult<()> {
+        let shape = match self.shape.len() {
+            0 => "()".to_string(),
+            1 => format!("({},)", self.shape[0]),
+            _ => format!(
+                "({})",
+                self.shape.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+        };
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+            NPY_DESCR, shape
+        );
+
+        // Magic (6) + version (2) + header length (2) + header must be a multiple of 64 bytes,
+        // with the header terminated by a newline
+        let unpadded = NPY_MAGIC.len() + 4 + header.len() + 1;
+        let padding = (64 - unpadded % 64) % 64;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        let header_len = u16::try_from(header.len()).map_err(|_| {
+            TensorError::InvalidOperation("npy header too large for format version 1.0".to_string())
+        })?;
+
+        let mut bytes = Vec::with_capacity(NPY_MAGIC.len() + 4 + header.len() + self.data.len() * 4);
+        bytes.extend_from_slice(NPY_MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&header_len.to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for value in &self.data {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        fs::write(path, bytes).map_err(|e| TensorError::IoError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Reads a little-endian `<f4` C-ordered `.npy` file (format version 1.0 or 2.0)
+    pub fn load_npy(path: &Path) -> TensorResult<Tensor> {
+        let bytes = fs::read(path).map_err(|e| TensorError::IoError(format!("{}: {}", path.display(), e)))?;
+        let invalid = |msg: &str| TensorError::InvalidOperation(format!("invalid npy file: {}", msg));
+
+        if bytes.len() < 10 || &bytes[..6] != NPY_MAGIC {
+            return Err(invalid("missing magic string"));
+        }
+
+        let (header_start, header_len) = match bytes[6] {
+            1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+            2 if bytes.len() >= 12 => (12, u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize),
+            _ => return Err(invalid("unsupported format version")),
+        };
+        let data_start = header_start + header_len;
+        if bytes.len() < data_start {
+            return Err(invalid("truncated header"));
+        }
+
+        let header = std::str::from_utf8(&bytes[header_start..data_start])
+            .map_err(|_| invalid("header is not valid ASCII"))?;
+
+        let descr = Self::npy_header_value(header, "descr").ok_or_else(|| invalid("missing descr"))?;
+        if descr.trim_matches('\'') != NPY_DESCR {
+            return Err(invalid(&format!("unsupported dtype {}, expected {}", descr, NPY_DESCR)));
+        }
+
+        let fortran_order = Self::npy_header_value(header, "fortran_order").ok_or_else(|| invalid("missing fortran_order"))?;
+        if fortran_order != "False" {
+            return Err(invalid("fortran-ordered arrays are not supported"));
+        }
+
+        let shape_str = Self::npy_header_value(header, "shape").ok_or_else(|| invalid("missing shape"))?;
+        let shape = shape_str
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .split(',')
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .map(|d| d.parse::<usize>().map_err(|_| invalid("malformed shape")))
+            .collect::<TensorResult<Vec<usize>>>()?;
+
+        let payload = &bytes[data_start..];
+        if payload.len() % 4 != 0 {
+            return Err(invalid("data length is not a multiple of 4 bytes"));
+        }
+        let data = payload
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+
+        Tensor::new(data, &shape)
+    }
+
+    // Extracts the raw value text following `'key':` in an npy header dictionary
+    fn npy_header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+        let pattern = format!("'{}':", key);
+        let rest = header[header.find(&pattern)? + pattern.len()..].trim_start();
+        let end = if rest.starts_with('(') {
+            rest.find(')')? + 1
+        } else {
+            rest.find(',').unwrap_or(rest.len())
+        };
+        Some(rest[..end].trim())
+    }
+}
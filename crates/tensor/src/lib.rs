@@ -1,11 +1,20 @@
 pub mod error;
+pub mod element;
 pub mod tensor;
 pub mod simd;
 pub mod ops;
+pub mod io;
+pub mod tns;
 use std::fmt;
 
 pub use error::{TensorError, TensorResult};
-pub use tensor::Tensor;
+pub use element::Element;
+pub use tensor::{Tensor, TensorGeneric};
+pub use simd::simd_capabilities;
+
+// Element-wise operations run sequentially below this many elements. For small tensors the
+// cost of splitting work across rayon threads outweighs the arithmetic itself.
+pub const PARALLEL_THRESHOLD: usize = 32_768;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExecutionMode {
@@ -25,3 +34,711 @@ impl fmt::Display for ExecutionMode {
         write!(f, "{}", mode_str)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("tensor_test_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let path = temp_path("round_trip.npy");
+        let tensor = Tensor::new(vec![1.0, -2.5, 3.25, 0.0, 5.0, 6.5], &[2, 3]).unwrap();
+
+        tensor.save_npy(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let loaded = Tensor::load_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+        assert!(header.starts_with("{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }"));
+        assert!(header.ends_with('\n'));
+        assert_eq!(bytes.len(), 10 + header_len + 6 * 4);
+
+        assert_eq!(loaded.shape(), &[2, 3]);
+        assert_eq!(loaded.data(), tensor.data());
+    }
+
+    #[test]
+    fn test_npy_one_dimensional_shape() {
+        let path = temp_path("vector.npy");
+        let tensor = Tensor::new(vec![1.0, 2.0, 3.0], &[3]).unwrap();
+
+        tensor.save_npy(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let loaded = Tensor::load_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let header = String::from_utf8_lossy(&bytes[10..]);
+        assert!(header.contains("'shape': (3,)"));
+        assert_eq!(loaded.shape(), &[3]);
+        assert_eq!(loaded.data(), &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_load_npy_rejects_other_dtypes() {
+        let path = temp_path("f8.npy");
+        let mut header = "{'descr': '<f8', 'fortran_order': False, 'shape': (1,), }".to_string();
+        header.push_str(&" ".repeat(64 - (10 + header.len() + 1) % 64));
+        header.push('\n');
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&1.0f64.to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+
+        let result = Tensor::load_npy(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(TensorError::InvalidOperation(_))));
+    }
+
+    #[test]
+    fn test_scalar_multiply_simd_matches_single() {
+        use crate::tns::{ComputeMode, Tensor as SimdTensor};
+
+        let size = 1_000_000;
+        let data: Vec<f32> = (0..size).map(|i| (i % 1000) as f32 * 0.5 - 250.0).collect();
+        let tensor = SimdTensor::new(data, &[size]).unwrap();
+
+        let single = tensor.scalar_multiply(1.75, ComputeMode::Single);
+        let simd = tensor.scalar_multiply(1.75, ComputeMode::SimdMultiThread);
+
+        assert_eq!(simd.shape(), &[size]);
+        assert_eq!(simd.data(), single.data());
+
+        // Length not divisible by the SIMD width exercises the scalar tail
+        let odd = SimdTensor::new((0..1003).map(|i| i as f32).collect(), &[1003]).unwrap();
+        assert_eq!(
+            odd.scalar_multiply(-2.0, ComputeMode::SimdMultiThread).data(),
+            odd.scalar_multiply(-2.0, ComputeMode::Single).data()
+        );
+    }
+
+    #[test]
+    fn test_simd_matmul_fma_matches_mul_add() {
+        let processor = simd::SimdProcessor::new();
+        if !(processor.supports_avx2 && processor.supports_fma) {
+            return;
+        }
+
+        let a = Tensor::random(&[64, 64], 7);
+        let b = Tensor::random(&[64, 64], 11);
+
+        let mul_add = unsafe { simd::SIMDOps::matrix_multiply_kernel(&a, &b, false) }.unwrap();
+        let reference = a.multiply(&b, ExecutionMode::Sequential).unwrap();
+        for (x, y) in mul_add.data().iter().zip(reference.data()) {
+            assert!((x - y).abs() < 1e-4);
+        }
+
+        let fused = unsafe { simd::SIMDOps::matrix_multiply_kernel(&a, &b, true) }.unwrap();
+        assert_eq!(fused.shape(), &[64, 64]);
+        for (x, y) in fused.data().iter().zip(mul_add.data()) {
+            assert!((x - y).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_tensor_operators_pick_mode_by_size() {
+        use crate::tns::{ComputeMode, Tensor as SimdTensor};
+
+        let a = SimdTensor::new(vec![1.0, 2.0, 3.0], &[3]).unwrap();
+        let b = SimdTensor::new(vec![0.5, 0.5, 0.5], &[3]).unwrap();
+        assert!(matches!(a.auto_compute_mode(), ComputeMode::Single));
+        assert_eq!((&a + &b).unwrap().data(), &[1.5, 2.5, 3.5]);
+        assert_eq!((&a * &b).unwrap().data(), &[0.5, 1.0, 1.5]);
+
+        let size = PARALLEL_THRESHOLD * 2;
+        let large_a = SimdTensor::new((0..size).map(|i| i as f32).collect(), &[size]).unwrap();
+        let large_b = SimdTensor::fill(&[size], 2.0);
+        assert!(matches!(large_a.auto_compute_mode(), ComputeMode::SimdMultiThread));
+        let sum = (&large_a + &large_b).unwrap();
+        assert_eq!(sum.data(), large_a.add(&large_b, ComputeMode::Single).unwrap().data());
+    }
+
+    #[test]
+    fn test_flatten_and_squeeze() {
+        let t = Tensor::new(vec![1.0, 2.0, 3.0], &[1, 3, 1]).unwrap();
+
+        let squeezed = t.squeeze();
+        assert_eq!(squeezed.shape(), &[3]);
+        assert_eq!(squeezed.rank(), 1);
+        assert_eq!(squeezed.data(), t.data());
+
+        assert_eq!(t.squeeze_axis(2).unwrap().shape(), &[1, 3]);
+        assert!(t.squeeze_axis(1).is_err());
+        assert!(t.squeeze_axis(3).is_err());
+
+        let m = Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+        let flat = m.flatten();
+        assert_eq!(flat.shape(), &[6]);
+        assert_eq!(flat.data(), m.data());
+    }
+
+    #[test]
+    fn test_unsqueeze() {
+        let v = Tensor::new(vec![1.0, 2.0, 3.0], &[3]).unwrap();
+
+        let row = v.unsqueeze(0).unwrap();
+        assert_eq!(row.shape(), &[1, 3]);
+        assert_eq!(row.rank(), 2);
+        assert_eq!(v.unsqueeze(1).unwrap().shape(), &[3, 1]);
+        assert!(v.unsqueeze(2).is_err());
+    }
+
+    #[test]
+    fn test_map_relu() {
+        let t = Tensor::new(vec![-2.0, -0.5, 0.0, 1.5, 3.0, -7.0], &[2, 3]).unwrap();
+        let relu = t.map(|x| x.max(0.0));
+        assert_eq!(relu.shape(), &[2, 3]);
+        assert_eq!(relu.data(), &[0.0, 0.0, 0.0, 1.5, 3.0, 0.0]);
+
+        let size = PARALLEL_THRESHOLD * 2;
+        let large = Tensor::new((0..size).map(|i| i as f32 - 1000.0).collect(), &[size]).unwrap();
+        let mapped = large.map(|x| x.max(0.0));
+        assert!(mapped.data().iter().zip(large.data()).all(|(&m, &x)| m == x.max(0.0)));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let t = Tensor::new(vec![-3.0, -1.0, 0.25, 0.9, 1.0, 4.0], &[3, 2]).unwrap();
+        let clamped = t.clamp(-1.0, 1.0);
+        assert_eq!(clamped.shape(), &[3, 2]);
+        assert!(clamped.data().iter().all(|&x| (-1.0..=1.0).contains(&x)));
+        assert_eq!(clamped.data(), &[-1.0, -1.0, 0.25, 0.9, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let u = Tensor::new(vec![1.0, 2.0, 3.0], &[3]).unwrap();
+        let v = Tensor::new(vec![4.0, 5.0], &[2, 1]).unwrap();
+
+        let outer = u.outer(&v).unwrap();
+        assert_eq!(outer.shape(), &[3, 2]);
+        assert_eq!(outer.data(), &[4.0, 5.0, 8.0, 10.0, 12.0, 15.0]);
+
+        let m = Tensor::zeros(&[2, 2]);
+        assert!(u.outer(&m).is_err());
+    }
+
+    #[test]
+    fn test_eye_and_diag() {
+        let eye = Tensor::eye(3);
+        assert_eq!(eye.shape(), &[3, 3]);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(eye.data()[i * 3 + j], if i == j { 1.0 } else { 0.0 });
+            }
+        }
+
+        let diag = Tensor::diag(&[1.0, 2.0, 3.0]);
+        assert_eq!(diag.shape(), &[3, 3]);
+        assert_eq!(diag.data(), &[1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn test_nested_round_trip() {
+        let nested = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+        let t = Tensor::from_nested(nested.clone()).unwrap();
+        assert_eq!(t.shape(), &[2, 3]);
+        assert_eq!(t.data(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(t.to_nested().unwrap(), nested);
+
+        assert!(Tensor::from_nested(vec![vec![1.0, 2.0], vec![3.0]]).is_err());
+        assert!(Tensor::zeros(&[2, 2, 2]).to_nested().is_err());
+    }
+
+    fn std_dev(tensor: &Tensor) -> f32 {
+        let n = tensor.size() as f32;
+        let mean = tensor.sum() / n;
+        (tensor.data().iter().map(|x| (x - mean).powi(2)).sum::<f32>() / n).sqrt()
+    }
+
+    #[test]
+    fn test_xavier_and_he_scale() {
+        let (fan_out, fan_in) = (300, 400);
+
+        let xavier = Tensor::xavier(&[fan_out, fan_in], fan_in, fan_out, 42).unwrap();
+        let limit = (6.0 / (fan_in + fan_out) as f32).sqrt();
+        assert!(xavier.data().iter().all(|x| x.abs() <= limit));
+        let expected = limit / 3f32.sqrt();
+        assert!((std_dev(&xavier) - expected).abs() / expected < 0.05);
+
+        let he = Tensor::he(&[fan_out, fan_in], fan_in, 42).unwrap();
+        let expected = (2.0 / fan_in as f32).sqrt();
+        assert!((std_dev(&he) - expected).abs() / expected < 0.05);
+
+        assert!(Tensor::xavier(&[10], 10, 1, 0).is_err());
+        assert!(Tensor::he(&[2, 3, 4], 3, 0).is_err());
+    }
+
+    #[test]
+    fn test_mean_max_min() {
+        let t = Tensor::new(vec![3.0, -1.0, 4.0, 1.0, -5.0, 9.0], &[2, 3]).unwrap();
+        assert!((t.mean() - 11.0 / 6.0).abs() < 1e-6);
+        assert_eq!(t.max().unwrap(), 9.0);
+        assert_eq!(t.min().unwrap(), -5.0);
+
+        let size = PARALLEL_THRESHOLD + 5;
+        let large = Tensor::new((0..size).map(|i| (i % 100) as f32 - 50.0).collect(), &[size]).unwrap();
+        assert_eq!(large.max().unwrap(), 49.0);
+        assert_eq!(large.min().unwrap(), -50.0);
+
+        let empty = Tensor::new(Vec::new(), &[0]).unwrap();
+        assert!(empty.max().is_err());
+        assert!(empty.min().is_err());
+    }
+
+    #[test]
+    fn test_shape_size_overflow_is_reported() {
+        let err = Tensor::new(vec![0.0; 2], &[usize::MAX, 2]).unwrap_err();
+        assert!(matches!(err, TensorError::InvalidOperation(ref msg) if msg.contains("shape size overflow")));
+
+        // A wrapping product would have matched this length
+        assert!(Tensor::new(vec![0.0; 0], &[usize::MAX / 2 + 1, 2]).is_err());
+        assert!(crate::tns::Tensor::new(vec![0.0; 2], &[usize::MAX, 2]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "shape size overflow")]
+    fn test_zeros_panics_on_shape_overflow() {
+        Tensor::zeros(&[usize::MAX, 2]);
+    }
+
+    #[test]
+    fn test_try_constructors_report_shape_overflow() {
+        use crate::tns::Tensor as SimdTensor;
+
+        let overflow = [usize::MAX, 2];
+        let is_overflow = |err: TensorError| {
+            matches!(err, TensorError::InvalidOperation(ref msg) if msg.contains("shape size overflow"))
+        };
+        assert!(is_overflow(Tensor::try_zeros(&overflow).unwrap_err()));
+        assert!(is_overflow(Tensor::try_ones(&overflow).unwrap_err()));
+        assert!(is_overflow(Tensor::try_fill(&overflow, 2.0).unwrap_err()));
+        assert!(is_overflow(SimdTensor::try_zeros(&overflow).unwrap_err()));
+        assert!(is_overflow(SimdTensor::try_ones(&overflow).unwrap_err()));
+        assert!(is_overflow(SimdTensor::try_fill(&overflow, 2.0).unwrap_err()));
+
+        assert_eq!(Tensor::try_fill(&[2, 3], 1.5).unwrap(), Tensor::fill(&[2, 3], 1.5));
+        assert_eq!(SimdTensor::try_ones(&[4]).unwrap().data(), SimdTensor::ones(&[4]).data());
+    }
+
+    #[test]
+    fn test_hadamard_div() {
+        let a = Tensor::new(vec![2.0, 9.0, -4.0, 1.0], &[2, 2]).unwrap();
+        let b = Tensor::new(vec![4.0, 3.0, 2.0, -0.5], &[2, 2]).unwrap();
+        assert_eq!(a.hadamard_div(&b).unwrap().data(), &[0.5, 3.0, -2.0, -2.0]);
+
+        let zero = Tensor::new(vec![1.0, 1.0, 0.0, 1.0], &[2, 2]).unwrap();
+        assert_eq!(
+            a.hadamard_div(&zero).unwrap_err(),
+            TensorError::InvalidOperation("Division by zero at index 2".to_string())
+        );
+        assert!(a.hadamard_div(&Tensor::ones(&[4])).is_err());
+    }
+
+    #[test]
+    fn test_pad_constant() {
+        let t = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let padded = t.pad(&[1, 1], &[1, 1], 0.0).unwrap();
+        assert_eq!(padded.shape(), &[4, 4]);
+        assert_eq!(padded.data(), &[
+            0.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 2.0, 0.0,
+            0.0, 3.0, 4.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+        ]);
+
+        let uneven = t.pad(&[0, 2], &[1, 0], -1.0).unwrap();
+        assert_eq!(uneven.shape(), &[3, 4]);
+        assert_eq!(uneven.data(), &[-1.0, -1.0, 1.0, 2.0, -1.0, -1.0, 3.0, 4.0, -1.0, -1.0, -1.0, -1.0]);
+
+        assert!(t.pad(&[1], &[1, 1], 0.0).is_err());
+        assert!(Tensor::ones(&[3]).pad(&[1], &[1], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_cumsum_along_axes() {
+        let t = Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+
+        let down = t.cumsum(0).unwrap();
+        assert_eq!(down.shape(), &[2, 3]);
+        assert_eq!(down.data(), &[1.0, 2.0, 3.0, 5.0, 7.0, 9.0]);
+
+        let across = t.cumsum(1).unwrap();
+        assert_eq!(across.data(), &[1.0, 3.0, 6.0, 4.0, 9.0, 15.0]);
+
+        assert!(t.cumsum(2).is_err());
+    }
+
+    #[test]
+    fn test_scalar_matmul_matches_simd() {
+        let a = Tensor::new((0..35).map(|i| (i % 7) as f32 * 0.5 - 1.0).collect(), &[5, 7]).unwrap();
+        let b = Tensor::new((0..63).map(|i| (i % 5) as f32 * 0.25).collect(), &[7, 9]).unwrap();
+        let scalar = simd::SIMDOps::matrix_multiply_scalar(&a, &b).unwrap();
+        let simd = simd::SIMDOps::matrix_multiply(&a, &b).unwrap();
+        assert_eq!(scalar.shape(), &[5, 9]);
+        for (s, v) in scalar.data().iter().zip(simd.data()) {
+            assert!((s - v).abs() < 1e-5);
+        }
+
+        let v = Tensor::new((0..7).map(|i| i as f32).collect(), &[7, 1]).unwrap();
+        let scalar = simd::SIMDOps::matrix_vector_multiply_scalar(&a, &v).unwrap();
+        let simd = simd::SIMDOps::matrix_vector_multiply(&a, &v).unwrap();
+        for (s, v) in scalar.data().iter().zip(simd.data()) {
+            assert!((s - v).abs() < 1e-4);
+        }
+        assert!(simd::SIMDOps::matrix_multiply_scalar(&a, &a).is_err());
+    }
+
+    #[test]
+    fn test_simd_capabilities() {
+        let (width, avx2, _avx512, _fma) = simd_capabilities();
+        assert!([4, 8, 16].contains(&width));
+        assert_eq!(avx2, width >= 8);
+    }
+
+    #[test]
+    fn test_generic_f64_tensor() {
+        let a = TensorGeneric::<f64>::new(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let b = TensorGeneric::<f64>::fill(&[2, 2], 0.5);
+        let sum = (&a + &b).unwrap();
+        assert_eq!(sum.data(), &[1.5, 2.5, 3.5, 4.5]);
+        assert_eq!(sum.shape(), &[2, 2]);
+        assert_eq!(sum.sum(), 12.0);
+        assert_eq!(a.transpose().unwrap().data(), &[1.0, 3.0, 2.0, 4.0]);
+
+        // Past the parallel threshold the f64 path runs through rayon
+        let n = PARALLEL_THRESHOLD + 3;
+        let big = TensorGeneric::<f64>::ones(&[n]);
+        let doubled = (&big + &big).unwrap();
+        assert!(doubled.data().iter().all(|&x| x == 2.0));
+        assert!((&a + &big).is_err());
+    }
+
+    #[test]
+    fn test_repeat_along_axes() {
+        let bias = Tensor::new(vec![1.0, 2.0, 3.0], &[1, 3]).unwrap();
+        let tiled = bias.repeat(0, 3).unwrap();
+        assert_eq!(tiled.shape(), &[3, 3]);
+        assert_eq!(tiled.data(), &[1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+
+        let wide = bias.repeat(1, 2).unwrap();
+        assert_eq!(wide.shape(), &[1, 6]);
+        assert_eq!(wide.data(), &[1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+
+        assert!(bias.repeat(2, 2).is_err());
+        assert!(bias.repeat(0, 0).is_err());
+    }
+
+    #[test]
+    fn test_one_hot() {
+        let target = Tensor::one_hot(2, 4).unwrap();
+        assert_eq!(target.shape(), &[4, 1]);
+        assert_eq!(target.data(), &[0.0, 0.0, 1.0, 0.0]);
+        assert!(Tensor::one_hot(4, 4).is_err());
+
+        let batch = Tensor::one_hot_batch(&[1, 0, 2], 3).unwrap();
+        assert_eq!(batch.shape(), &[3, 3]);
+        assert_eq!(batch.data(), &[0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert!(Tensor::one_hot_batch(&[0, 5], 3).is_err());
+    }
+
+    #[test]
+    fn test_cosine_similarity_and_l2_distance() {
+        let x = Tensor::new(vec![1.0, 0.0, 0.0], &[3]).unwrap();
+        let y = Tensor::new(vec![0.0, 2.0, 0.0], &[3]).unwrap();
+        let v = Tensor::new(vec![1.0, 2.0, 3.0], &[3]).unwrap();
+
+        assert!(x.cosine_similarity(&y).unwrap().abs() < 1e-6);
+        assert!((v.cosine_similarity(&v).unwrap() - 1.0).abs() < 1e-6);
+        assert!((v.cosine_similarity(&v.scale(-2.0)).unwrap() + 1.0).abs() < 1e-6);
+        // The product of the squared norms (about 2e42) would overflow f32
+        let large = v.scale(1e10);
+        assert!((large.cosine_similarity(&large).unwrap() - 1.0).abs() < 1e-6);
+
+        let a = Tensor::new(vec![0.0, 0.0], &[2]).unwrap();
+        let b = Tensor::new(vec![3.0, 4.0], &[2]).unwrap();
+        assert_eq!(a.l2_distance(&b).unwrap(), 5.0);
+        assert!(a.cosine_similarity(&b).is_err());
+        assert!(v.l2_distance(&b).is_err());
+        assert!(v.cosine_similarity(&x.unsqueeze(1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_masked_fill_and_where_gt() {
+        let x = Tensor::new(vec![-1.0, 2.0, -3.0, 4.0], &[2, 2]).unwrap();
+        let negative = x.where_gt(0.0, 0.0, 1.0);
+        assert_eq!(negative.data(), &[1.0, 0.0, 1.0, 0.0]);
+
+        let relu = x.masked_fill(&negative, 0.0).unwrap();
+        assert_eq!(relu.data(), &[0.0, 2.0, 0.0, 4.0]);
+        assert_eq!(relu.shape(), &[2, 2]);
+
+        assert!(x.masked_fill(&negative.flatten(), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_in_place_ops_match_out_of_place() {
+        let a = Tensor::random(&[3, 4], 1);
+        let b = Tensor::random(&[3, 4], 2);
+
+        let mut sum = a.clone();
+        sum.add_assign(&b).unwrap();
+        assert_eq!(sum.data(), (&a + &b).unwrap().data());
+
+        let mut diff = a.clone();
+        diff.sub_assign(&b).unwrap();
+        assert_eq!(diff.data(), (&a - &b).unwrap().data());
+
+        let mut big = Tensor::ones(&[PARALLEL_THRESHOLD + 1]);
+        big.scale_mut(3.0);
+        assert!(big.data().iter().all(|&x| x == 3.0));
+        big.add_assign(&Tensor::ones(&[PARALLEL_THRESHOLD + 1])).unwrap();
+        assert!(big.data().iter().all(|&x| x == 4.0));
+
+        assert!(sum.add_assign(&Tensor::ones(&[4, 3])).is_err());
+        assert_eq!(sum.data(), (&a + &b).unwrap().data());
+    }
+
+    #[test]
+    fn test_linear_combination() {
+        let a = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let b = Tensor::new(vec![10.0, 0.0, -10.0, 1.0], &[2, 2]).unwrap();
+        let mixed = Tensor::linear_combination(&[&a, &b], &[0.3, 0.7]).unwrap();
+        assert_eq!(mixed.shape(), &[2, 2]);
+        let expected = [7.3, 0.6, -6.1, 1.9];
+        for (x, e) in mixed.data().iter().zip(expected) {
+            assert!((x - e).abs() < 1e-5);
+        }
+
+        assert!(Tensor::linear_combination(&[&a, &b], &[1.0]).is_err());
+        assert!(Tensor::linear_combination(&[&a, &a.flatten()], &[1.0, 1.0]).is_err());
+        assert!(Tensor::linear_combination(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_prod_and_norms() {
+        let v = Tensor::new(vec![3.0, -4.0, 1.0, -2.0], &[4]).unwrap();
+        assert_eq!(v.prod(), 24.0);
+        assert_eq!(v.norm(1.0), 10.0);
+        assert!((v.norm(2.0) - 30f32.sqrt()).abs() < 1e-6);
+        assert_eq!(v.norm(f32::INFINITY), 4.0);
+        assert!((v.norm(3.0) - 100f32.cbrt()).abs() < 1e-4);
+
+        let big = Tensor::fill(&[PARALLEL_THRESHOLD + 4], -0.5);
+        assert!((big.norm(1.0) - 0.5 * big.size() as f32).abs() < 1.0);
+        assert_eq!(big.norm(f32::INFINITY), 0.5);
+        assert_eq!(Tensor::ones(&[PARALLEL_THRESHOLD]).prod(), 1.0);
+    }
+
+    #[test]
+    fn test_clip_by_norm() {
+        let grad = Tensor::new(vec![3.0, 4.0], &[2]).unwrap();
+        let clipped = grad.clip_by_norm(1.0);
+        assert!((clipped.norm(2.0) - 1.0).abs() < 1e-6);
+        assert!((clipped.data()[0] - 0.6).abs() < 1e-6);
+
+        let untouched = grad.clip_by_norm(10.0);
+        assert_eq!(untouched.data(), grad.data());
+        assert_eq!(grad.clip_by_norm(5.0).data(), grad.data());
+    }
+
+    #[test]
+    fn test_blocked_transpose_matches_naive() {
+        for &(rows, cols) in &[(513, 257), (33, 5)] {
+            let data: Vec<f32> = (0..rows * cols).map(|x| x as f32).collect();
+            let tensor = Tensor::new(data.clone(), &[rows, cols]).unwrap();
+
+            let mut expected = vec![0.0; rows * cols];
+            for i in 0..rows {
+                for j in 0..cols {
+                    expected[j * rows + i] = data[i * cols + j];
+                }
+            }
+
+            let transposed = tensor.transpose().unwrap();
+            assert_eq!(transposed.shape(), &[cols, rows]);
+            assert_eq!(transposed.data(), expected.as_slice());
+        }
+
+        assert!(Tensor::new(vec![1.0; 8], &[2, 2, 2]).unwrap().transpose().is_err());
+    }
+
+    #[test]
+    fn test_allclose_scales_with_magnitude() {
+        let a = Tensor::new(vec![1000.0, -2500.0], &[2]).unwrap();
+        let b = Tensor::new(vec![1000.001, -2500.002], &[2]).unwrap();
+        assert!(a != b);
+        assert!(a.allclose(&b, 1e-5, 1e-8));
+        assert!(!a.allclose(&b, 0.0, 1e-6));
+
+        let small = Tensor::new(vec![1e-9, 0.0], &[2]).unwrap();
+        let zeros = Tensor::zeros(&[2]);
+        assert!(small.allclose(&zeros, 1e-5, 1e-8));
+        assert!(!small.allclose(&Tensor::zeros(&[2, 1]), 1e-5, 1e-8));
+        assert!(!Tensor::new(vec![f32::NAN], &[1]).unwrap().allclose(&Tensor::new(vec![f32::NAN], &[1]).unwrap(), 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_matmul_matches_parallel_simd_multiply() {
+        use crate::tns::Tensor as SimdTensor;
+
+        // The first product is below PARALLEL_THRESHOLD and stays single-threaded
+        for (m, k, n) in [(37, 19, 23), (70, 40, 30)] {
+            let a = Tensor::random(&[m, k], 3);
+            let b = Tensor::random(&[k, n], 5);
+            let expected = a.multiply(&b, ExecutionMode::ParallelSIMD).unwrap();
+
+            let lhs = SimdTensor::new(a.data().to_vec(), a.shape()).unwrap();
+            let rhs = SimdTensor::new(b.data().to_vec(), b.shape()).unwrap();
+            let product = lhs.matmul(&rhs).unwrap();
+
+            let product = Tensor::new(product.data().to_vec(), product.shape()).unwrap();
+            assert!(product.allclose(&expected, 1e-5, 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_flip_along_each_axis() {
+        let t = Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+
+        let vertical = t.flip(0).unwrap();
+        assert_eq!(vertical.shape(), &[2, 3]);
+        assert_eq!(vertical.data(), &[4.0, 5.0, 6.0, 1.0, 2.0, 3.0]);
+
+        let horizontal = t.flip(1).unwrap();
+        assert_eq!(horizontal.data(), &[3.0, 2.0, 1.0, 6.0, 5.0, 4.0]);
+        assert_eq!(horizontal.flip(1).unwrap(), t);
+
+        assert!(matches!(t.flip(2), Err(TensorError::IndexOutOfBounds(_))));
+        assert!(Tensor::new(vec![1.0, 2.0], &[2]).unwrap().flip(0).is_err());
+    }
+
+    #[test]
+    fn test_into_data_returns_buffer() {
+        let t = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let ptr = t.data().as_ptr();
+        let data = t.into_data();
+        assert_eq!(data, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(data.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_add_and_sub_scalar() {
+        let t = Tensor::new(vec![1.0, -2.0, 3.5, 0.0], &[2, 2]).unwrap();
+        let shifted = t.add_scalar(1.5);
+        assert_eq!(shifted.shape(), &[2, 2]);
+        assert_eq!(shifted.data(), &[2.5, -0.5, 5.0, 1.5]);
+        assert_eq!(t.sub_scalar(1.0).data(), &[0.0, -3.0, 2.5, -1.0]);
+        assert_eq!(shifted.sub_scalar(1.5), t);
+
+        // Large enough to take the parallel path
+        let large = Tensor::zeros(&[PARALLEL_THRESHOLD + 1]);
+        assert!(large.add_scalar(2.0).data().iter().all(|&x| x == 2.0));
+    }
+
+    #[test]
+    fn test_normalize_axis_zero_mean_unit_variance() {
+        let t = Tensor::new(vec![1.0, 10.0, -3.0, 2.0, 20.0, 5.0, 3.0, 60.0, 4.0], &[3, 3]).unwrap();
+
+        let moments = |values: &[f32]| {
+            let n = values.len() as f32;
+            let mean = values.iter().sum::<f32>() / n;
+            let var = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f32>() / n;
+            (mean, var)
+        };
+
+        let by_column = t.normalize_axis(0, 1e-6).unwrap();
+        for j in 0..3 {
+            let column: Vec<f32> = (0..3).map(|i| by_column.data()[i * 3 + j]).collect();
+            let (mean, var) = moments(&column);
+            assert!(mean.abs() < 1e-5 && (var - 1.0).abs() < 1e-4, "column {}: {} {}", j, mean, var);
+        }
+
+        let by_row = t.normalize_axis(1, 1e-6).unwrap();
+        for row in by_row.data().chunks(3) {
+            let (mean, var) = moments(row);
+            assert!(mean.abs() < 1e-5 && (var - 1.0).abs() < 1e-4);
+        }
+
+        // A constant lane has zero variance; epsilon keeps the result finite
+        let constant = Tensor::fill(&[2, 2], 4.0).normalize_axis(1, 1e-5).unwrap();
+        assert!(constant.data().iter().all(|&x| x == 0.0));
+        assert!(t.normalize_axis(2, 1e-5).is_err());
+    }
+
+    #[test]
+    fn test_split_into_chunks() {
+        let t = Tensor::new((0..40).map(|x| x as f32).collect(), &[10, 4]).unwrap();
+        let batches = t.split(0, 3).unwrap();
+        let shapes: Vec<&[usize]> = batches.iter().map(|b| b.shape()).collect();
+        assert_eq!(shapes, vec![&[3, 4][..], &[3, 4], &[3, 4], &[1, 4]]);
+        assert_eq!(batches[3].data(), &[36.0, 37.0, 38.0, 39.0]);
+
+        let columns = t.split(1, 3).unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[1].shape(), &[10, 1]);
+        assert_eq!(&columns[0].data()[..6], &[0.0, 1.0, 2.0, 4.0, 5.0, 6.0]);
+        assert_eq!(&columns[1].data()[..3], &[3.0, 7.0, 11.0]);
+
+        assert!(t.split(0, 0).is_err());
+        assert!(matches!(t.split(2, 1), Err(TensorError::IndexOutOfBounds(_))));
+    }
+
+    #[test]
+    fn test_roll_wraps_around() {
+        let t = Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], &[1, 5]).unwrap();
+        assert_eq!(t.roll(2, 1).unwrap().data(), &[4.0, 5.0, 1.0, 2.0, 3.0]);
+        assert_eq!(t.roll(-1, 1).unwrap().data(), &[2.0, 3.0, 4.0, 5.0, 1.0]);
+        assert_eq!(t.roll(5, 1).unwrap(), t);
+        assert_eq!(t.roll(3, 0).unwrap(), t);
+
+        let m = Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[3, 2]).unwrap();
+        assert_eq!(m.roll(1, 0).unwrap().data(), &[5.0, 6.0, 1.0, 2.0, 3.0, 4.0]);
+        assert!(m.roll(1, 2).is_err());
+    }
+
+    #[test]
+    fn test_batched_dot_per_row() {
+        let a = Tensor::new(vec![
+            1.0, 2.0, 3.0, 4.0,
+            0.0, 1.0, 0.0, 1.0,
+            -1.0, 2.0, -3.0, 4.0,
+        ], &[3, 4]).unwrap();
+        let b = Tensor::new(vec![
+            1.0, 1.0, 1.0, 1.0,
+            5.0, 6.0, 7.0, 8.0,
+            2.0, 2.0, 2.0, 2.0,
+        ], &[3, 4]).unwrap();
+
+        let dots = a.batched_dot(&b).unwrap();
+        assert_eq!(dots.shape(), &[3, 1]);
+        assert_eq!(dots.data(), &[10.0, 14.0, 4.0]);
+
+        assert!(a.batched_dot(&Tensor::zeros(&[3, 3])).is_err());
+        assert!(Tensor::zeros(&[4]).batched_dot(&Tensor::zeros(&[4])).is_err());
+    }
+
+    #[test]
+    fn test_broadcast_to_repeats_unit_axes() {
+        let column = Tensor::new(vec![1.0, 2.0, 3.0], &[3, 1]).unwrap();
+        let expanded = column.broadcast_to(&[3, 4]).unwrap();
+        assert_eq!(expanded.shape(), &[3, 4]);
+        assert_eq!(expanded.data(), &[1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0]);
+
+        let row = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], &[1, 4]).unwrap();
+        let expanded = row.broadcast_to(&[3, 4]).unwrap();
+        assert_eq!(expanded.data(), &[1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(row.broadcast_to(&[1, 4]).unwrap(), row);
+
+        assert!(matches!(row.broadcast_to(&[3, 5]), Err(TensorError::ShapeMismatch(_))));
+        assert!(matches!(row.broadcast_to(&[4]), Err(TensorError::DimensionError(_))));
+    }
+}
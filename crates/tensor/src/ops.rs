@@ -1,37 +1,37 @@
 use std::ops::{Add, Sub};
 use std::thread;
-use crate::tensor::Tensor;
+use crate::element::Element;
+use crate::tensor::{Tensor, TensorGeneric};
 use crate::error::{TensorError, TensorResult};
 use crate::simd::{SIMDOps, RawPointerWrapper};
 use crate::ExecutionMode;
 
-impl Add for &Tensor {
-    type Output = TensorResult<Tensor>;
+impl<T: Element> Add for &TensorGeneric<T> {
+    type Output = TensorResult<TensorGeneric<T>>;
 
-    fn add(self, rhs: &Tensor) -> TensorResult<Tensor> {
+    fn add(self, rhs: &TensorGeneric<T>) -> TensorResult<TensorGeneric<T>> {
         self.check_same_shape(rhs)?;
-        let data = self.data.iter().zip(rhs.data.iter()).map(|(a, b)| a + b).collect();
-        Tensor::new(data, &self.shape)
+        let mut data = vec![T::zero(); self.data.len()];
+        T::add_slice(&self.data, &rhs.data, &mut data);
+        TensorGeneric::new(data, &self.shape)
     }
 }
 
-impl Sub for &Tensor {
-    type Output = TensorResult<Tensor>;
+impl<T: Element> Sub for &TensorGeneric<T> {
+    type Output = TensorResult<TensorGeneric<T>>;
 
-    fn sub(self, rhs: &Tensor) -> TensorResult<Tensor> {
+    fn sub(self, rhs: &TensorGeneric<T>) -> TensorResult<TensorGeneric<T>> {
         self.check_same_shape(rhs)?;
-        let data = self.data.iter().zip(rhs.data.iter()).map(|(a, b)| a - b).collect();
-        Tensor::new(data, &self.shape)
+        let data = self.data.iter().zip(rhs.data.iter()).map(|(&a, &b)| a - b).collect();
+        TensorGeneric::new(data, &self.shape)
     }
 }
 
-impl Sub for Tensor {
-    type Output = TensorResult<Tensor>;
+impl<T: Element> Sub for TensorGeneric<T> {
+    type Output = TensorResult<TensorGeneric<T>>;
 
-    fn sub(self, rhs: Tensor) -> TensorResult<Tensor> {
-        self.check_same_shape(&rhs)?;
-        let data = self.data.iter().zip(rhs.data.iter()).map(|(a, b)| a - b).collect();
-        Tensor::new(data, &self.shape)
+    fn sub(self, rhs: TensorGeneric<T>) -> TensorResult<TensorGeneric<T>> {
+        &self - &rhs
     }
 }
 
@@ -1,4 +1,4 @@
-use std::arch::x86_64::{_mm256_add_ps, _mm256_loadu_ps, _mm256_mul_ps, _mm256_setzero_ps, _mm256_storeu_ps};
+use std::arch::x86_64::{_mm256_add_ps, _mm256_fmadd_ps, _mm256_loadu_ps, _mm256_mul_ps, _mm256_set1_ps, _mm256_setzero_ps, _mm256_storeu_ps};
 use std::sync::Arc;
 use std::thread;
 use crate::tensor::Tensor;
@@ -14,8 +14,134 @@ unsafe impl Sync for RawPointerWrapper {}
 
 impl RawPointerWrapper {
     pub unsafe fn modify_at(&self, index: usize, value: f32) {
-        let ptr = self.raw.add(index);
-        *ptr = value;
+        unsafe {
+            let ptr = self.raw.add(index);
+            *ptr = value;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimdProcessor {
+    pub simd_width: usize,
+    pub supports_avx2: bool,
+    pub supports_avx512: bool,
+    pub supports_fma: bool,
+}
+
+impl Default for SimdProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `(simd_width, avx2, avx512, fma)` for the current CPU.
+pub fn simd_capabilities() -> (usize, bool, bool, bool) {
+    let processor = SimdProcessor::new();
+    (
+        processor.simd_width,
+        processor.supports_avx2,
+        processor.supports_avx512,
+        processor.supports_fma,
+    )
+}
+
+impl SimdProcessor {
+    pub fn new() -> Self {
+        let supports_avx2 = is_x86_feature_detected!("avx2");
+        let supports_avx512 = is_x86_feature_detected!("avx512f");
+        let supports_fma = is_x86_feature_detected!("fma");
+        // f32 lanes per vector: 256-bit AVX2 kernels, otherwise the SSE2 baseline
+        let simd_width = if supports_avx2 { 8 } else { 4 };
+
+        SimdProcessor {
+            simd_width,
+            supports_avx2,
+            supports_avx512,
+            supports_fma,
+        }
+    }
+
+    pub fn add_slice(&self, a: &[f32], b: &[f32], out: &mut [f32]) {
+        if self.supports_avx2 {
+            unsafe { Self::add_avx2(a, b, out) }
+        } else {
+            for ((r, x), y) in out.iter_mut().zip(a).zip(b) {
+                *r = x + y;
+            }
+        }
+    }
+
+    pub fn mul_slice(&self, a: &[f32], b: &[f32], out: &mut [f32]) {
+        if self.supports_avx2 {
+            unsafe { Self::mul_avx2(a, b, out) }
+        } else {
+            for ((r, x), y) in out.iter_mut().zip(a).zip(b) {
+                *r = x * y;
+            }
+        }
+    }
+
+    // Multiplies every element by `scalar`, broadcasting it into a register instead of a temporary slice
+    pub fn scale_slice(&self, a: &[f32], scalar: f32, out: &mut [f32]) {
+        if self.supports_avx2 {
+            unsafe { Self::scale_avx2(a, scalar, out) }
+        } else {
+            for (r, x) in out.iter_mut().zip(a) {
+                *r = x * scalar;
+            }
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn add_avx2(a: &[f32], b: &[f32], out: &mut [f32]) {
+        let len = out.len().min(a.len()).min(b.len());
+        let complete_chunks = len / 8;
+        unsafe {
+            for j in 0..complete_chunks {
+                let offset = j * 8;
+                let a_vec = _mm256_loadu_ps(a.as_ptr().add(offset));
+                let b_vec = _mm256_loadu_ps(b.as_ptr().add(offset));
+                _mm256_storeu_ps(out.as_mut_ptr().add(offset), _mm256_add_ps(a_vec, b_vec));
+            }
+        }
+        for j in complete_chunks * 8..len {
+            out[j] = a[j] + b[j];
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_avx2(a: &[f32], b: &[f32], out: &mut [f32]) {
+        let len = out.len().min(a.len()).min(b.len());
+        let complete_chunks = len / 8;
+        unsafe {
+            for j in 0..complete_chunks {
+                let offset = j * 8;
+                let a_vec = _mm256_loadu_ps(a.as_ptr().add(offset));
+                let b_vec = _mm256_loadu_ps(b.as_ptr().add(offset));
+                _mm256_storeu_ps(out.as_mut_ptr().add(offset), _mm256_mul_ps(a_vec, b_vec));
+            }
+        }
+        for j in complete_chunks * 8..len {
+            out[j] = a[j] * b[j];
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn scale_avx2(a: &[f32], scalar: f32, out: &mut [f32]) {
+        let len = out.len().min(a.len());
+        let complete_chunks = len / 8;
+        unsafe {
+            let scalar_vec = _mm256_set1_ps(scalar);
+            for j in 0..complete_chunks {
+                let offset = j * 8;
+                let a_vec = _mm256_loadu_ps(a.as_ptr().add(offset));
+                _mm256_storeu_ps(out.as_mut_ptr().add(offset), _mm256_mul_ps(a_vec, scalar_vec));
+            }
+        }
+        for j in complete_chunks * 8..len {
+            out[j] = a[j] * scalar;
+        }
     }
 }
 
@@ -23,23 +149,15 @@ pub struct SIMDOps;
 
 impl SIMDOps {
     pub fn matrix_vector_multiply(matrix: &Tensor, vector: &Tensor) -> TensorResult<Tensor> {
-        if !matrix.is_matrix() || !vector.is_column_vector() {
-            return Err(TensorError::DimensionError(
-                "Expected matrix and column vector".to_string()
-            ));
-        }
-
-        if matrix.shape()[1] != vector.shape()[0] {
-            return Err(TensorError::ShapeMismatch(format!(
-                "Matrix cols {} must match vector rows {}",
-                matrix.shape()[1], vector.shape()[0]
-            )));
+        Self::check_matrix_vector(matrix, vector)?;
+        if !SimdProcessor::new().supports_avx2 {
+            return Self::matrix_vector_multiply_scalar(matrix, vector);
         }
 
         let mut res = vec![0.0f32; matrix.rows()];
         let cols = matrix.cols();
 
-        for i in 0..matrix.rows() {
+        for (i, r) in res.iter_mut().enumerate() {
             unsafe {
                 let mut total = 0.0f32;
                 let mut elem = _mm256_setzero_ps();
@@ -66,24 +184,16 @@ impl SIMDOps {
                 total += values[0] + values[1] + values[2] + values[3] + 
                         values[4] + values[5] + values[6] + values[7];
 
-                res[i] = total;
+                *r = total;
             }
         }
         Tensor::new(res, &[matrix.rows(), 1])
     }
 
     pub fn matrix_vector_multiply_parallel(matrix: &Tensor, vector: &Tensor, nb_threads: usize) -> TensorResult<Tensor> {
-        if !matrix.is_matrix() || !vector.is_column_vector() {
-            return Err(TensorError::DimensionError(
-                "Expected matrix and column vector".to_string()
-            ));
-        }
-
-        if matrix.shape()[1] != vector.shape()[0] {
-            return Err(TensorError::ShapeMismatch(format!(
-                "Matrix cols {} must match vector rows {}",
-                matrix.shape()[1], vector.shape()[0]
-            )));
+        Self::check_matrix_vector(matrix, vector)?;
+        if !SimdProcessor::new().supports_avx2 {
+            return Self::matrix_vector_multiply_scalar(matrix, vector);
         }
 
         let mut res = vec![0.0f32; matrix.rows()];
@@ -147,18 +257,18 @@ impl SIMDOps {
     }
 
     pub fn matrix_multiply(a: &Tensor, b: &Tensor) -> TensorResult<Tensor> {
-        if !a.is_matrix() || !b.is_matrix() {
-            return Err(TensorError::DimensionError(
-                "Both tensors must be 2D matrices".to_string()
-            ));
+        Self::check_matrix_matrix(a, b)?;
+        let processor = SimdProcessor::new();
+        if !(processor.supports_avx2 && processor.supports_fma) {
+            return Self::matrix_multiply_scalar(a, b);
         }
+        unsafe { Self::matrix_multiply_kernel(a, b, true) }
+    }
 
-        if a.shape()[1] != b.shape()[0] {
-            return Err(TensorError::ShapeMismatch(format!(
-                "Matrix dimensions don't match: {}x{} * {}x{}",
-                a.shape()[0], a.shape()[1], b.shape()[0], b.shape()[1]
-            )));
-        }
+    // Fuses the multiply and accumulate into `_mm256_fmadd_ps` when `use_fma` is set.
+    // Safety: callers must have checked the shapes and that the CPU supports AVX2 and FMA.
+    #[target_feature(enable = "avx2,fma")]
+    pub(crate) unsafe fn matrix_multiply_kernel(a: &Tensor, b: &Tensor, use_fma: bool) -> TensorResult<Tensor> {
 
         let mut res = vec![0.0f32; a.rows() * b.cols()];
         let transposed = b.transpose()?;
@@ -174,8 +284,11 @@ impl SIMDOps {
                         let offset = j * 8;
                         let a_vec = _mm256_loadu_ps(a.data.as_ptr().add(i * a.cols() + offset));
                         let b_vec = _mm256_loadu_ps(transposed.data.as_ptr().add(k * transposed.cols() + offset));
-                        let prod = _mm256_mul_ps(a_vec, b_vec);                   
-                        elem = _mm256_add_ps(prod, elem);
+                        elem = if use_fma {
+                            _mm256_fmadd_ps(a_vec, b_vec, elem)
+                        } else {
+                            _mm256_add_ps(_mm256_mul_ps(a_vec, b_vec), elem)
+                        };
                     }
     
                     let remaining = a.cols() % 8;
@@ -199,17 +312,9 @@ impl SIMDOps {
     }
 
     pub fn matrix_multiply_parallel(a: &Tensor, b: &Tensor, nb_threads: usize) -> TensorResult<Tensor> {
-        if !a.is_matrix() || !b.is_matrix() {
-            return Err(TensorError::DimensionError(
-                "Both tensors must be 2D matrices".to_string()
-            ));
-        }
-
-        if a.shape()[1] != b.shape()[0] {
-            return Err(TensorError::ShapeMismatch(format!(
-                "Matrix dimensions don't match: {}x{} * {}x{}",
-                a.shape()[0], a.shape()[1], b.shape()[0], b.shape()[1]
-            )));
+        Self::check_matrix_matrix(a, b)?;
+        if !SimdProcessor::new().supports_avx2 {
+            return Self::matrix_multiply_scalar(a, b);
         }
 
         let transposed = b.transpose()?;
@@ -276,4 +381,62 @@ impl SIMDOps {
         }
         Tensor::new(res, &[a.rows(), b.cols()])
     }
-}
\ No newline at end of file
+
+    // Portable fallbacks used when the CPU lacks AVX2
+    pub fn matrix_vector_multiply_scalar(matrix: &Tensor, vector: &Tensor) -> TensorResult<Tensor> {
+        Self::check_matrix_vector(matrix, vector)?;
+        let cols = matrix.cols();
+        let res = matrix.data.chunks(cols.max(1))
+            .take(matrix.rows())
+            .map(|row| row.iter().zip(vector.data.iter()).map(|(a, b)| a * b).sum())
+            .collect();
+        Tensor::new(res, &[matrix.rows(), 1])
+    }
+
+    pub fn matrix_multiply_scalar(a: &Tensor, b: &Tensor) -> TensorResult<Tensor> {
+        Self::check_matrix_matrix(a, b)?;
+        let (m, k, n) = (a.rows(), a.cols(), b.cols());
+        let mut res = vec![0.0f32; m * n];
+        for i in 0..m {
+            for l in 0..k {
+                let a_il = a.data[i * k + l];
+                for j in 0..n {
+                    res[i * n + j] += a_il * b.data[l * n + j];
+                }
+            }
+        }
+        Tensor::new(res, &[m, n])
+    }
+
+    fn check_matrix_vector(matrix: &Tensor, vector: &Tensor) -> TensorResult<()> {
+        if !matrix.is_matrix() || !vector.is_column_vector() {
+            return Err(TensorError::DimensionError(
+                "Expected matrix and column vector".to_string()
+            ));
+        }
+
+        if matrix.shape()[1] != vector.shape()[0] {
+            return Err(TensorError::ShapeMismatch(format!(
+                "Matrix cols {} must match vector rows {}",
+                matrix.shape()[1], vector.shape()[0]
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_matrix_matrix(a: &Tensor, b: &Tensor) -> TensorResult<()> {
+        if !a.is_matrix() || !b.is_matrix() {
+            return Err(TensorError::DimensionError(
+                "Both tensors must be 2D matrices".to_string()
+            ));
+        }
+
+        if a.shape()[1] != b.shape()[0] {
+            return Err(TensorError::ShapeMismatch(format!(
+                "Matrix dimensions don't match: {}x{} * {}x{}",
+                a.shape()[0], a.shape()[1], b.shape()[0], b.shape()[1]
+            )));
+        }
+        Ok(())
+    }
+}
@@ -2,17 +2,39 @@ use crate::error::{TensorError, TensorResult};
 use rand_pcg::Pcg64;
 use rand::distributions::{Distribution, Uniform};
 use rand::SeedableRng;
+use rayon::prelude::*;
+use crate::PARALLEL_THRESHOLD;
+use crate::element::Element;
+
+// Edge of the square tiles `transpose` copies at a time, so reads and writes both stay in cache
+const TRANSPOSE_TILE: usize = 32;
+
+// Number of elements for `shape`, or an error if the product overflows usize
+pub(crate) fn checked_size(shape: &[usize]) -> TensorResult<usize> {
+    shape.iter().try_fold(1usize, |acc, &d| acc.checked_mul(d)).ok_or_else(|| {
+        TensorError::InvalidOperation(format!("shape size overflow for shape {:?}", shape))
+    })
+}
+
+// `zeros`, `ones`, `fill` and `random` keep their infallible signatures and panic on an overflowing
+// shape; the `try_*` constructors return the error instead
+pub(crate) fn expect_size(shape: &[usize]) -> usize {
+    checked_size(shape).unwrap_or_else(|e| panic!("{}", e))
+}
 
 #[derive(Debug, Clone)]
-pub struct Tensor {
-    pub(crate) data: Vec<f32>,
+pub struct TensorGeneric<T> {
+    pub(crate) data: Vec<T>,
     pub(crate) rank: usize,
     pub(crate) shape: Vec<usize>,
 }
 
-impl Tensor {
-    pub fn new(data: Vec<f32>, shape: &[usize]) -> TensorResult<Self> {
-        let expected_size: usize = shape.iter().product();
+// The SIMD kernels, random initialisers and .npy I/O work on f32 tensors
+pub type Tensor = TensorGeneric<f32>;
+
+impl<T: Element> TensorGeneric<T> {
+    pub fn new(data: Vec<T>, shape: &[usize]) -> TensorResult<Self> {
+        let expected_size = checked_size(shape)?;
         if data.len() != expected_size {
             return Err(TensorError::ShapeMismatch(format!(
                 "Data length {} does not match shape {:?} (expected {})",
@@ -20,7 +42,7 @@ impl Tensor {
             )));
         }
         
-        Ok(Tensor {
+        Ok(TensorGeneric {
             data,
             shape: shape.to_vec(),
             rank: shape.len(),
@@ -28,32 +50,45 @@ impl Tensor {
     }
 
     pub fn zeros(shape: &[usize]) -> Self {
-        let size: usize = shape.iter().product();
-        Tensor {
-            data: vec![0.0; size],
+        let size = expect_size(shape);
+        TensorGeneric {
+            data: vec![T::zero(); size],
             shape: shape.to_vec(),
             rank: shape.len(),
         }
     }
 
     pub fn ones(shape: &[usize]) -> Self {
-        let size: usize = shape.iter().product();
-        Tensor {
-            data: vec![1.0; size],
+        let size = expect_size(shape);
+        TensorGeneric {
+            data: vec![T::one(); size],
             shape: shape.to_vec(),
             rank: shape.len(),
         }
     }
 
-    pub fn fill(shape: &[usize], value: f32) -> Self {
-        let size: usize = shape.iter().product();
-        Tensor {
+    pub fn fill(shape: &[usize], value: T) -> Self {
+        let size = expect_size(shape);
+        TensorGeneric {
             data: vec![value; size],
             shape: shape.to_vec(),
             rank: shape.len(),
         }
     }
 
+    // Fallible `zeros`, `ones` and `fill`: an overflowing shape is an InvalidOperation error
+    pub fn try_zeros(shape: &[usize]) -> TensorResult<Self> {
+        Self::try_fill(shape, T::zero())
+    }
+
+    pub fn try_ones(shape: &[usize]) -> TensorResult<Self> {
+        Self::try_fill(shape, T::one())
+    }
+
+    pub fn try_fill(shape: &[usize], value: T) -> TensorResult<Self> {
+        Self::new(vec![value; checked_size(shape)?], shape)
+    }
+
     pub fn shape(&self) -> &[usize] {
         &self.shape
     }
@@ -66,15 +101,20 @@ impl Tensor {
         self.data.len()
     }
 
-    pub fn data(&self) -> &[f32] {
+    pub fn data(&self) -> &[T] {
         &self.data
     }
 
-    pub fn data_mut(&mut self) -> &mut [f32] {
+    pub fn data_mut(&mut self) -> &mut [T] {
         &mut self.data
     }
 
-    pub (crate) fn check_same_shape(&self, other: &Tensor) -> TensorResult<()> {
+    // Takes the row-major backing buffer without copying it
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    pub (crate) fn check_same_shape(&self, other: &Self) -> TensorResult<()> {
         if self.shape != other.shape {
             return Err(TensorError::ShapeMismatch(format!(
                 "Tensors have different shapes: {:?} vs {:?}",
@@ -83,6 +123,32 @@ impl Tensor {
         }
         Ok(())
     }
+    // Conversion to and from row-major nested vectors
+    pub fn from_nested(data: Vec<Vec<T>>) -> TensorResult<Self> {
+        let rows = data.len();
+        let cols = data.first().map_or(0, Vec::len);
+        if let Some((i, row)) = data.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(TensorError::ShapeMismatch(format!(
+                "Row {} has length {}, expected {}", i, row.len(), cols
+            )));
+        }
+
+        Self::new(data.into_iter().flatten().collect(), &[rows, cols])
+    }
+
+    pub fn to_nested(&self) -> TensorResult<Vec<Vec<T>>> {
+        if self.rank != 2 {
+            return Err(TensorError::DimensionError(format!(
+                "to_nested requires a rank-2 tensor, got shape {:?}", self.shape
+            )));
+        }
+        if self.shape[1] == 0 {
+            return Ok(vec![Vec::new(); self.shape[0]]);
+        }
+
+        Ok(self.data.chunks(self.shape[1]).map(<[T]>::to_vec).collect())
+    }
+
      // Legacy compatibility methods
      pub fn rows(&self) -> usize {
         if self.rank >= 1 { self.shape[0] } else { 1 }
@@ -97,29 +163,53 @@ impl Tensor {
     }
 
     // Creation methods
-    pub fn scalar(value: f32) -> Self {
-        Tensor {
+    pub fn scalar(value: T) -> Self {
+        TensorGeneric {
             data: vec![value],
             shape: vec![1],
             rank: 1,
         }
     }
 
-    pub fn random(shape: &[usize], seed: u64) -> Self {
-        let mut rng = Pcg64::seed_from_u64(seed);
-        let uniform = Uniform::new(0.0, 1.0);
-        let size: usize = shape.iter().product();
-        let data = (0..size)
-            .map(|_| uniform.sample(&mut rng))
-            .collect::<Vec<f32>>();
+    pub fn eye(n: usize) -> Self {
+        Self::diag(&vec![T::one(); n])
+    }
 
-        Tensor {
+    pub fn diag(values: &[T]) -> Self {
+        let n = values.len();
+        let mut data = vec![T::zero(); n * n];
+        for (i, &value) in values.iter().enumerate() {
+            data[i * n + i] = value;
+        }
+
+        TensorGeneric {
             data,
-            shape: shape.to_vec(),
-            rank: shape.len(),
+            shape: vec![n, n],
+            rank: 2,
         }
     }
 
+    // [num_classes, 1] column vector with a one at `index`
+    pub fn one_hot(index: usize, num_classes: usize) -> TensorResult<Self> {
+        Self::one_hot_batch(&[index], num_classes)
+    }
+
+    // [num_classes, batch] matrix whose column j is the one-hot encoding of indices[j]
+    pub fn one_hot_batch(indices: &[usize], num_classes: usize) -> TensorResult<Self> {
+        if let Some(&index) = indices.iter().find(|&&index| index >= num_classes) {
+            return Err(TensorError::IndexOutOfBounds(format!(
+                "Class index {} out of range for {} classes", index, num_classes
+            )));
+        }
+
+        let batch = indices.len();
+        let mut data = vec![T::zero(); checked_size(&[num_classes, batch])?];
+        for (j, &index) in indices.iter().enumerate() {
+            data[index * batch + j] = T::one();
+        }
+        Self::new(data, &[num_classes, batch])
+    }
+
     // Utility methods
     pub fn print(&self) {
         if self.rank == 2 {
@@ -135,7 +225,7 @@ impl Tensor {
         }
     }
 
-    pub fn transpose(&self) -> TensorResult<Tensor> {
+    pub fn transpose(&self) -> TensorResult<Self> {
         if self.rank != 2 {
             return Err(TensorError::DimensionError(
                 "Transpose only supported for 2D tensors".to_string()
@@ -144,33 +234,266 @@ impl Tensor {
 
         let rows = self.shape[0];
         let cols = self.shape[1];
-        let mut data = vec![0.0; rows * cols];
-        
-        for i in 0..rows {
-            for j in 0..cols {
-                data[j * rows + i] = self.data[i * cols + j];
+        let mut data = vec![T::zero(); rows * cols];
+        if data.is_empty() {
+            return Self::new(data, &[cols, rows]);
+        }
+
+        // Each chunk is one band of TRANSPOSE_TILE output rows, filled tile by tile
+        let fill_band = |(band, out): (usize, &mut [T])| {
+            let j_start = band * TRANSPOSE_TILE;
+            let j_end = (j_start + TRANSPOSE_TILE).min(cols);
+            for i_start in (0..rows).step_by(TRANSPOSE_TILE) {
+                let i_end = (i_start + TRANSPOSE_TILE).min(rows);
+                for j in j_start..j_end {
+                    for i in i_start..i_end {
+                        out[(j - j_start) * rows + i] = self.data[i * cols + j];
+                    }
+                }
             }
+        };
+
+        let band_len = TRANSPOSE_TILE * rows;
+        if data.len() >= PARALLEL_THRESHOLD {
+            data.par_chunks_mut(band_len).enumerate().for_each(fill_band);
+        } else {
+            data.chunks_mut(band_len).enumerate().for_each(fill_band);
         }
-        
-        Tensor::new(data, &[cols, rows])
+
+        Self::new(data, &[cols, rows])
     }
 
-    pub fn scale(&self, scalar: f32) -> Self {
+    pub fn scale(&self, scalar: T) -> Self {
         let data = self.data.iter().map(|&x| x * scalar).collect();
-        Tensor {
+        TensorGeneric {
             data,
             shape: self.shape.clone(),
             rank: self.rank,
         }
     }
 
-    pub fn sum(&self) -> f32 {
-        self.data.iter().sum()
+    // Shift every element by a constant
+    pub fn add_scalar(&self, scalar: T) -> Self {
+        self.map(|x| x + scalar)
+    }
+
+    pub fn sub_scalar(&self, scalar: T) -> Self {
+        self.map(|x| x - scalar)
+    }
+
+    // sum(weights[i] * tensors[i]) over same-shaped tensors
+    pub fn linear_combination(tensors: &[&Self], weights: &[T]) -> TensorResult<Self> {
+        if tensors.len() != weights.len() {
+            return Err(TensorError::ShapeMismatch(format!(
+                "Got {} tensors but {} weights", tensors.len(), weights.len()
+            )));
+        }
+        let first = tensors.first().ok_or_else(|| {
+            TensorError::InvalidOperation("linear_combination needs at least one tensor".to_string())
+        })?;
+        for tensor in &tensors[1..] {
+            first.check_same_shape(tensor)?;
+        }
+
+        let combine = |i: usize| {
+            tensors.iter().zip(weights).fold(T::zero(), |acc, (t, &w)| acc + w * t.data[i])
+        };
+        let data = if first.data.len() >= PARALLEL_THRESHOLD {
+            (0..first.data.len()).into_par_iter().map(combine).collect()
+        } else {
+            (0..first.data.len()).map(combine).collect()
+        };
+        Self::new(data, &first.shape)
+    }
+
+    // In-place variants of `+`, `-` and `scale` that reuse the existing buffer
+    pub fn add_assign(&mut self, other: &Self) -> TensorResult<()> {
+        self.zip_assign(other, |a, b| a + b)
+    }
+
+    pub fn sub_assign(&mut self, other: &Self) -> TensorResult<()> {
+        self.zip_assign(other, |a, b| a - b)
+    }
+
+    pub fn scale_mut(&mut self, scalar: T) {
+        if self.data.len() >= PARALLEL_THRESHOLD {
+            self.data.par_iter_mut().for_each(|x| *x = *x * scalar);
+        } else {
+            self.data.iter_mut().for_each(|x| *x = *x * scalar);
+        }
+    }
+
+    fn zip_assign(&mut self, other: &Self, f: impl Fn(T, T) -> T + Sync) -> TensorResult<()> {
+        self.check_same_shape(other)?;
+        if self.data.len() >= PARALLEL_THRESHOLD {
+            self.data.par_iter_mut().zip(other.data.par_iter()).for_each(|(a, &b)| *a = f(*a, b));
+        } else {
+            self.data.iter_mut().zip(other.data.iter()).for_each(|(a, &b)| *a = f(*a, b));
+        }
+        Ok(())
+    }
+
+    // Applies `f` to every element, in parallel once the tensor reaches PARALLEL_THRESHOLD
+    pub fn map<F: Fn(T) -> T + Sync>(&self, f: F) -> Self {
+        let data = if self.data.len() >= PARALLEL_THRESHOLD {
+            self.data.par_iter().map(|&x| f(x)).collect()
+        } else {
+            self.data.iter().map(|&x| f(x)).collect()
+        };
+        TensorGeneric {
+            data,
+            shape: self.shape.clone(),
+            rank: self.rank,
+        }
+    }
+
+    pub fn clamp(&self, min: T, max: T) -> Self {
+        self.map(|x| num_traits::clamp(x, min, max))
+    }
+
+    // Replaces the elements where `mask` is nonzero with `value`
+    pub fn masked_fill(&self, mask: &Self, value: T) -> TensorResult<Self> {
+        self.check_same_shape(mask)?;
+        let data = self.data.iter().zip(mask.data.iter())
+            .map(|(&x, m)| if m.is_zero() { x } else { value })
+            .collect();
+        Self::new(data, &self.shape)
+    }
+
+    pub fn where_gt(&self, threshold: T, if_true: T, if_false: T) -> Self {
+        self.map(|x| if x > threshold { if_true } else { if_false })
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    pub fn prod(&self) -> T {
+        self.map_reduce(T::one(), |x| x, |a, b| a * b)
+    }
+
+    // p-norm (sum |x|^p)^(1/p); p = 1 is the sum of absolute values, p = infinity the largest one
+    pub fn norm(&self, p: T) -> T {
+        if p.is_infinite() {
+            self.map_reduce(T::zero(), T::abs, T::max)
+        } else if p == T::one() {
+            self.map_reduce(T::zero(), T::abs, |a, b| a + b)
+        } else if p == T::one() + T::one() {
+            self.map_reduce(T::zero(), |x| x * x, |a, b| a + b).sqrt()
+        } else {
+            self.map_reduce(T::zero(), |x| x.abs().powf(p), |a, b| a + b).powf(p.recip())
+        }
+    }
+
+    // Scales the tensor down so its L2 norm is at most `max_norm` (global-norm gradient clipping)
+    pub fn clip_by_norm(&self, max_norm: T) -> Self {
+        let norm = self.norm(T::one() + T::one());
+        if norm > max_norm {
+            self.scale(max_norm / norm)
+        } else {
+            self.clone()
+        }
+    }
+
+    fn map_reduce(&self, identity: T, map: impl Fn(T) -> T + Sync, reduce: impl Fn(T, T) -> T + Sync) -> T {
+        if self.data.len() >= PARALLEL_THRESHOLD {
+            self.data.par_iter().map(|&x| map(x)).reduce(|| identity, &reduce)
+        } else {
+            self.data.iter().fold(identity, |acc, &x| reduce(acc, map(x)))
+        }
+    }
+
+    // Mean of all elements (NaN for an empty tensor)
+    pub fn mean(&self) -> T {
+        let total = if self.data.len() >= PARALLEL_THRESHOLD {
+            self.data.par_iter().copied().reduce(T::zero, |a, b| a + b)
+        } else {
+            self.sum()
+        };
+        total / T::from(self.data.len()).unwrap_or_else(T::nan)
+    }
+
+    pub fn max(&self) -> TensorResult<T> {
+        self.reduce_non_empty("max", T::max)
+    }
+
+    pub fn min(&self) -> TensorResult<T> {
+        self.reduce_non_empty("min", T::min)
+    }
+
+    fn reduce_non_empty(&self, op: &str, f: fn(T, T) -> T) -> TensorResult<T> {
+        let first = *self.data.first().ok_or_else(|| {
+            TensorError::InvalidOperation(format!("Cannot compute {} of an empty tensor", op))
+        })?;
+
+        if self.data.len() >= PARALLEL_THRESHOLD {
+            Ok(self.data.par_iter().copied().reduce(|| first, f))
+        } else {
+            Ok(self.data.iter().copied().fold(first, f))
+        }
+    }
+
+    // Running sums along `axis` (0 = down the rows, 1 = across the columns) of a 2D tensor
+    pub fn cumsum(&self, axis: usize) -> TensorResult<Self> {
+        let (rows, cols) = self.check_2d_axis(axis, "cumsum")?;
+
+        let mut data = self.data.clone();
+        if axis == 0 {
+            for i in 1..rows {
+                for j in 0..cols {
+                    data[i * cols + j] = data[i * cols + j] + data[(i - 1) * cols + j];
+                }
+            }
+        } else {
+            for row in data.chunks_mut(cols.max(1)) {
+                for j in 1..row.len() {
+                    row[j] = row[j] + row[j - 1];
+                }
+            }
+        }
+
+        Self::new(data, &self.shape)
+    }
+
+    // (x - mean) / sqrt(var + epsilon) with the population statistics taken along `axis` of a 2D
+    // tensor: 0 normalizes every column (batch norm), 1 every row (layer norm)
+    pub fn normalize_axis(&self, axis: usize, epsilon: T) -> TensorResult<Self> {
+        let (rows, cols) = self.check_2d_axis(axis, "normalize_axis")?;
+
+        let mut data = self.data.clone();
+        if data.is_empty() {
+            return Self::new(data, &self.shape);
+        }
+
+        if axis == 0 {
+            let count = T::from(rows).unwrap();
+            for j in 0..cols {
+                let mean = (0..rows).fold(T::zero(), |acc, i| acc + data[i * cols + j]) / count;
+                let var = (0..rows).fold(T::zero(), |acc, i| {
+                    let d = data[i * cols + j] - mean;
+                    acc + d * d
+                }) / count;
+                let inv_std = (var + epsilon).sqrt().recip();
+                for i in 0..rows {
+                    data[i * cols + j] = (data[i * cols + j] - mean) * inv_std;
+                }
+            }
+        } else {
+            let count = T::from(cols).unwrap();
+            for row in data.chunks_mut(cols) {
+                let mean = row.iter().fold(T::zero(), |acc, &x| acc + x) / count;
+                let var = row.iter().fold(T::zero(), |acc, &x| acc + (x - mean) * (x - mean)) / count;
+                let inv_std = (var + epsilon).sqrt().recip();
+                row.iter_mut().for_each(|x| *x = (*x - mean) * inv_std);
+            }
+        }
+
+        Self::new(data, &self.shape)
     }
 
     pub fn square(&self) -> Self {
-        let data = self.data.iter().map(|x| x * x).collect();
-        Tensor {
+        let data = self.data.iter().map(|&x| x * x).collect();
+        TensorGeneric {
             data,
             shape: self.shape.clone(),
             rank: self.rank,
@@ -196,10 +519,328 @@ impl Tensor {
     }
 
     // Element-wise multiplication (Hadamard product)
-    pub fn hadamard(&self, other: &Tensor) -> TensorResult<Tensor> {
+    pub fn hadamard(&self, other: &Self) -> TensorResult<Self> {
         self.check_same_shape(other)?;
-        let data = self.data.iter().zip(other.data.iter()).map(|(a, b)| a * b).collect();
-        Tensor::new(data, &self.shape)
+        let mut data = vec![T::zero(); self.data.len()];
+        T::mul_slice(&self.data, &other.data, &mut data);
+        Self::new(data, &self.shape)
+    }
+
+    // Element-wise division; any zero divisor is an error naming its flat index
+    pub fn hadamard_div(&self, other: &Self) -> TensorResult<Self> {
+        self.check_same_shape(other)?;
+        if let Some(index) = other.data.iter().position(|x| x.is_zero()) {
+            return Err(TensorError::InvalidOperation(format!(
+                "Division by zero at index {}", index
+            )));
+        }
+        let data = self.data.iter().zip(other.data.iter()).map(|(&a, &b)| a / b).collect();
+        Self::new(data, &self.shape)
+    }
+
+    // Sum of element-wise products of two same-shaped tensors
+    pub fn dot(&self, other: &Self) -> TensorResult<T> {
+        self.check_same_shape(other)?;
+        Ok(self.data.iter().zip(other.data.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+
+    // Row-wise dot products of two same-shaped [batch, d] tensors, as a [batch, 1] column
+    pub fn batched_dot(&self, other: &Self) -> TensorResult<Self> {
+        if self.rank != 2 {
+            return Err(TensorError::DimensionError(
+                "batched_dot only supported for 2D tensors".to_string()
+            ));
+        }
+        self.check_same_shape(other)?;
+
+        let (batch, d) = (self.shape[0], self.shape[1]);
+        let row_dot = |i: usize| {
+            let (a, b) = (&self.data[i * d..(i + 1) * d], &other.data[i * d..(i + 1) * d]);
+            a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+        };
+        let data = if self.data.len() >= PARALLEL_THRESHOLD {
+            (0..batch).into_par_iter().map(row_dot).collect()
+        } else {
+            (0..batch).map(row_dot).collect()
+        };
+
+        Self::new(data, &[batch, 1])
+    }
+
+    // dot(a, b) / (|a| |b|); undefined, and so an error, when either tensor is all zeros
+    pub fn cosine_similarity(&self, other: &Self) -> TensorResult<T> {
+        let dot = self.dot(other)?;
+        // Multiplying the two norms, rather than the squared norms, keeps large inputs from overflowing
+        let two = T::one() + T::one();
+        let norms = self.norm(two) * other.norm(two);
+        if norms.is_zero() {
+            return Err(TensorError::InvalidOperation(
+                "Cosine similarity is undefined for a zero tensor".to_string()
+            ));
+        }
+        Ok(dot / norms)
+    }
+
+    pub fn l2_distance(&self, other: &Self) -> TensorResult<T> {
+        let diff = (self - other)?;
+        Ok(diff.dot(&diff)?.sqrt())
+    }
+
+    // NumPy's allclose: same shape and |a - b| <= atol + rtol * |b| for every element.
+    // Unlike `==`, the tolerance scales with the magnitude of `other`; NaN is never close.
+    pub fn allclose(&self, other: &Self, rtol: T, atol: T) -> bool {
+        self.shape == other.shape
+            && self.data.iter().zip(other.data.iter()).all(|(&a, &b)| (a - b).abs() <= atol + rtol * b.abs())
+    }
+
+    // Outer product u * v^T of two vectors (rank-1 or column vectors)
+    pub fn outer(&self, other: &Self) -> TensorResult<Self> {
+        if !self.is_vector() || !other.is_vector() {
+            return Err(TensorError::DimensionError(format!(
+                "Outer product requires two vectors, got shapes {:?} and {:?}",
+                self.shape, other.shape
+            )));
+        }
+
+        let m = self.data.len();
+        let n = other.data.len();
+        let mut data = vec![T::zero(); m * n];
+        if data.is_empty() {
+            return Self::new(data, &[m, n]);
+        }
+        data.par_chunks_mut(n)
+            .zip(self.data.par_iter())
+            .for_each(|(row, &u)| {
+                for (r, &v) in row.iter_mut().zip(other.data.iter()) {
+                    *r = u * v;
+                }
+            });
+
+        Self::new(data, &[m, n])
+    }
+
+    // Constant padding; currently limited to rank-2 tensors
+    pub fn pad(&self, pad_before: &[usize], pad_after: &[usize], value: T) -> TensorResult<Self> {
+        if pad_before.len() != self.rank || pad_after.len() != self.rank {
+            return Err(TensorError::DimensionError(format!(
+                "Padding needs one entry per axis (rank {}), got {} and {}",
+                self.rank, pad_before.len(), pad_after.len()
+            )));
+        }
+        if self.rank != 2 {
+            return Err(TensorError::InvalidOperation(
+                "Padding is only supported for 2D tensors".to_string()
+            ));
+        }
+
+        let (rows, cols) = (self.shape[0], self.shape[1]);
+        let padded_rows = pad_before[0] + rows + pad_after[0];
+        let padded_cols = pad_before[1] + cols + pad_after[1];
+        let mut data = vec![value; checked_size(&[padded_rows, padded_cols])?];
+
+        for i in 0..rows {
+            let start = (i + pad_before[0]) * padded_cols + pad_before[1];
+            data[start..start + cols].copy_from_slice(&self.data[i * cols..(i + 1) * cols]);
+        }
+
+        Self::new(data, &[padded_rows, padded_cols])
+    }
+
+    // Concatenates `count` copies of a 2D tensor along `axis`
+    pub fn repeat(&self, axis: usize, count: usize) -> TensorResult<Self> {
+        let (rows, cols) = self.check_2d_axis(axis, "repeat")?;
+        if count == 0 {
+            return Err(TensorError::InvalidOperation(
+                "repeat count must be at least 1".to_string()
+            ));
+        }
+
+        let mut data = Vec::with_capacity(checked_size(&[rows, cols, count])?);
+        if axis == 0 {
+            for _ in 0..count {
+                data.extend_from_slice(&self.data);
+            }
+        } else {
+            for row in self.data.chunks(cols.max(1)).take(rows) {
+                for _ in 0..count {
+                    data.extend_from_slice(row);
+                }
+            }
+        }
+
+        let mut shape = self.shape.clone();
+        shape[axis] *= count;
+        Self::new(data, &shape)
+    }
+
+    // Cuts a 2D tensor into consecutive pieces of `chunk_size` along `axis`; the last may be smaller
+    pub fn split(&self, axis: usize, chunk_size: usize) -> TensorResult<Vec<Self>> {
+        let (rows, cols) = self.check_2d_axis(axis, "split")?;
+        if chunk_size == 0 {
+            return Err(TensorError::InvalidOperation(
+                "split chunk_size must be at least 1".to_string()
+            ));
+        }
+
+        let mut chunks = Vec::new();
+        for start in (0..self.shape[axis]).step_by(chunk_size) {
+            let len = chunk_size.min(self.shape[axis] - start);
+            let chunk = if axis == 0 {
+                Self::new(self.data[start * cols..(start + len) * cols].to_vec(), &[len, cols])?
+            } else {
+                let data = self.data.chunks(cols)
+                    .take(rows)
+                    .flat_map(|row| row[start..start + len].iter().copied())
+                    .collect();
+                Self::new(data, &[rows, len])?
+            };
+            chunks.push(chunk);
+        }
+
+        Ok(chunks)
+    }
+
+    // Reverses a 2D tensor along `axis`: 0 flips the row order (vertical), 1 each row (horizontal)
+    pub fn flip(&self, axis: usize) -> TensorResult<Self> {
+        let (rows, cols) = self.check_2d_axis(axis, "flip")?;
+
+        let row_iter = self.data.chunks(cols.max(1)).take(rows);
+        let data = if axis == 0 {
+            row_iter.rev().flatten().copied().collect()
+        } else {
+            row_iter.flat_map(|row| row.iter().rev()).copied().collect()
+        };
+
+        Self::new(data, &self.shape)
+    }
+
+    // Circular shift of a 2D tensor along `axis`: element k moves to (k + shift) mod len, so a
+    // negative shift moves elements towards the start
+    pub fn roll(&self, shift: isize, axis: usize) -> TensorResult<Self> {
+        let (rows, cols) = self.check_2d_axis(axis, "roll")?;
+
+        let len = self.shape[axis];
+        let mut data = self.data.clone();
+        if data.is_empty() {
+            return Self::new(data, &self.shape);
+        }
+
+        let offset = shift.rem_euclid(len as isize) as usize;
+        if axis == 0 {
+            data.rotate_right(offset * cols);
+        } else {
+            for row in data.chunks_mut(cols).take(rows) {
+                row.rotate_right(offset);
+            }
+        }
+
+        Self::new(data, &self.shape)
+    }
+
+    // Materializes the tensor expanded to `shape` by repeating its size-1 axes. The rank must
+    // match, and every other axis must already have the target size
+    pub fn broadcast_to(&self, shape: &[usize]) -> TensorResult<Self> {
+        if shape.len() != self.rank {
+            return Err(TensorError::DimensionError(format!(
+                "Cannot broadcast tensor of rank {} to shape {:?}", self.rank, shape
+            )));
+        }
+        if let Some(axis) = (0..self.rank).find(|&a| self.shape[a] != shape[a] && self.shape[a] != 1) {
+            return Err(TensorError::ShapeMismatch(format!(
+                "Cannot broadcast axis {} of size {} to size {} (shape {:?} to {:?})",
+                axis, self.shape[axis], shape[axis], self.shape, shape
+            )));
+        }
+
+        // Row-major strides of the source, with zero on the axes being repeated
+        let mut strides = vec![0; self.rank];
+        let mut stride = 1;
+        for axis in (0..self.rank).rev() {
+            if self.shape[axis] != 1 {
+                strides[axis] = stride;
+            }
+            stride *= self.shape[axis];
+        }
+
+        let source = |flat: usize| {
+            let mut remaining = flat;
+            let mut offset = 0;
+            for axis in (0..shape.len()).rev() {
+                offset += (remaining % shape[axis]) * strides[axis];
+                remaining /= shape[axis];
+            }
+            self.data[offset]
+        };
+
+        let size = checked_size(shape)?;
+        let data = if size >= PARALLEL_THRESHOLD {
+            (0..size).into_par_iter().map(source).collect()
+        } else {
+            (0..size).map(source).collect()
+        };
+        Self::new(data, shape)
+    }
+
+    // Shape manipulation (data is never reordered, only shape/rank change)
+    pub fn flatten(&self) -> Self {
+        self.reshaped(vec![self.data.len()])
+    }
+
+    pub fn squeeze(&self) -> Self {
+        self.reshaped(self.shape.iter().copied().filter(|&d| d != 1).collect())
+    }
+
+    pub fn squeeze_axis(&self, axis: usize) -> TensorResult<Self> {
+        if axis >= self.rank {
+            return Err(TensorError::IndexOutOfBounds(format!(
+                "Axis {} out of range for tensor of rank {}", axis, self.rank
+            )));
+        }
+        if self.shape[axis] != 1 {
+            return Err(TensorError::DimensionError(format!(
+                "Cannot squeeze axis {} of size {}", axis, self.shape[axis]
+            )));
+        }
+
+        let mut shape = self.shape.clone();
+        shape.remove(axis);
+        Ok(self.reshaped(shape))
+    }
+
+    pub fn unsqueeze(&self, axis: usize) -> TensorResult<Self> {
+        if axis > self.rank {
+            return Err(TensorError::IndexOutOfBounds(format!(
+                "Axis {} out of range for unsqueeze on tensor of rank {}", axis, self.rank
+            )));
+        }
+
+        let mut shape = self.shape.clone();
+        shape.insert(axis, 1);
+        Ok(self.reshaped(shape))
+    }
+
+    // Validates `axis` for the 2D-only axis operations and returns (rows, cols); `op` names the
+    // operation in the rank error
+    fn check_2d_axis(&self, axis: usize, op: &str) -> TensorResult<(usize, usize)> {
+        if axis >= self.rank {
+            return Err(TensorError::IndexOutOfBounds(format!(
+                "Axis {} out of range for tensor of rank {}", axis, self.rank
+            )));
+        }
+        if self.rank != 2 {
+            return Err(TensorError::DimensionError(format!(
+                "{} only supported for 2D tensors", op
+            )));
+        }
+        Ok((self.shape[0], self.shape[1]))
+    }
+
+    fn reshaped(&self, shape: Vec<usize>) -> Self {
+        TensorGeneric {
+            data: self.data.clone(),
+            rank: shape.len(),
+            shape,
+        }
     }
 
     // Check if tensor is a vector (column vector for matrix operations)
@@ -207,14 +848,79 @@ impl Tensor {
         self.rank == 2 && self.shape[1] == 1
     }
 
+    pub(crate) fn is_vector(&self) -> bool {
+        self.rank == 1 || self.is_column_vector()
+    }
+
     pub(crate) fn is_matrix(&self) -> bool {
         self.rank == 2
     }
 }
 
-impl PartialEq for Tensor {
+// Random initialisers sample f32 directly
+impl Tensor {
+    pub fn random(shape: &[usize], seed: u64) -> Self {
+        let mut rng = Pcg64::seed_from_u64(seed);
+        let uniform = Uniform::new(0.0, 1.0);
+        let size = expect_size(shape);
+        let data = (0..size)
+            .map(|_| uniform.sample(&mut rng))
+            .collect::<Vec<f32>>();
+
+        Tensor {
+            data,
+            shape: shape.to_vec(),
+            rank: shape.len(),
+        }
+    }
+
+    // Xavier/Glorot uniform weights: U(-limit, limit) with limit = sqrt(6 / (fan_in + fan_out))
+    pub fn xavier(shape: &[usize], fan_in: usize, fan_out: usize, seed: u64) -> TensorResult<Self> {
+        Self::check_weight_shape(shape, fan_in + fan_out)?;
+        let limit = (6.0 / (fan_in + fan_out) as f32).sqrt();
+        let mut rng = Pcg64::seed_from_u64(seed);
+        let uniform = Uniform::new_inclusive(-limit, limit);
+        let data = (0..shape[0] * shape[1])
+            .map(|_| uniform.sample(&mut rng))
+            .collect();
+
+        Tensor::new(data, shape)
+    }
+
+    // He/Kaiming normal weights: N(0, 2 / fan_in), sampled with the Box-Muller transform
+    pub fn he(shape: &[usize], fan_in: usize, seed: u64) -> TensorResult<Self> {
+        Self::check_weight_shape(shape, fan_in)?;
+        let std_dev = (2.0 / fan_in as f32).sqrt();
+        let mut rng = Pcg64::seed_from_u64(seed);
+        let uniform = Uniform::new(f32::EPSILON, 1.0);
+        let data = (0..shape[0] * shape[1])
+            .map(|_| {
+                let (u1, u2): (f32, f32) = (uniform.sample(&mut rng), uniform.sample(&mut rng));
+                std_dev * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos()
+            })
+            .collect();
+
+        Tensor::new(data, shape)
+    }
+
+    fn check_weight_shape(shape: &[usize], fan: usize) -> TensorResult<()> {
+        if shape.len() != 2 {
+            return Err(TensorError::DimensionError(format!(
+                "Weight initialization requires a rank-2 shape, got {:?}", shape
+            )));
+        }
+        if fan == 0 {
+            return Err(TensorError::InvalidOperation(
+                "Weight initialization requires a non-zero fan".to_string()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Element> PartialEq for TensorGeneric<T> {
     fn eq(&self, other: &Self) -> bool {
         self.shape == other.shape && 
-        self.data.iter().zip(other.data.iter()).all(|(a, b)| (a - b).abs() < 1e-6)
+        self.data.iter().zip(other.data.iter()).all(|(a, b)| (*a - *b).abs() < T::from(1e-6).unwrap())
     }
 }
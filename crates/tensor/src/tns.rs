@@ -1,5 +1,7 @@
 use crate::error::{TensorError, TensorResult};
-use crate::simd::SimdProcessor;
+use crate::simd::{SIMDOps, SimdProcessor};
+use crate::PARALLEL_THRESHOLD;
+use crate::tensor::{checked_size, expect_size};
 use rayon::prelude::*;
 use std::sync::Arc;
 use std::ops::{Add, Sub, Mul, Div};
@@ -22,7 +24,7 @@ pub struct Tensor {
 
 impl Tensor {
     pub fn new(data: Vec<f32>, shape: &[usize]) -> TensorResult<Self> {
-        let expected_size: usize = shape.iter().product();
+        let expected_size = checked_size(shape)?;
         if data.len() != expected_size {
             return Err(TensorError::ShapeMismatch(format!(
                 "Data length {} does not match shape {:?} (expected {})",
@@ -42,7 +44,7 @@ impl Tensor {
     }
 
     pub fn zeros(shape: &[usize]) -> Self {
-        let size: usize = shape.iter().product();
+        let size = expect_size(shape);
         let chunk_size = std::cmp::max(1000, num_cpus::get() * 100);
         
         Tensor {
@@ -55,7 +57,7 @@ impl Tensor {
     }
 
     pub fn ones(shape: &[usize]) -> Self {
-        let size: usize = shape.iter().product();
+        let size = expect_size(shape);
         let chunk_size = std::cmp::max(1000, num_cpus::get() * 100);
         
         Tensor {
@@ -68,7 +70,7 @@ impl Tensor {
     }
 
     pub fn fill(shape: &[usize], value: f32) -> Self {
-        let size: usize = shape.iter().product();
+        let size = expect_size(shape);
         let chunk_size = std::cmp::max(1000, num_cpus::get() * 100);
         
         Tensor {
@@ -80,6 +82,19 @@ impl Tensor {
         }
     }
 
+    // Fallible `zeros`, `ones` and `fill`: an overflowing shape is an InvalidOperation error
+    pub fn try_zeros(shape: &[usize]) -> TensorResult<Self> {
+        Self::try_fill(shape, 0.0)
+    }
+
+    pub fn try_ones(shape: &[usize]) -> TensorResult<Self> {
+        Self::try_fill(shape, 1.0)
+    }
+
+    pub fn try_fill(shape: &[usize], value: f32) -> TensorResult<Self> {
+        Self::new(vec![value; checked_size(shape)?], shape)
+    }
+
     pub fn shape(&self) -> &[usize] {
         &self.shape
     }
@@ -100,6 +115,15 @@ impl Tensor {
         &mut self.data
     }
 
+    // Mode used by the operator overloads: sequential for small tensors, SIMD + threads otherwise
+    pub fn auto_compute_mode(&self) -> ComputeMode {
+        if self.data.len() < PARALLEL_THRESHOLD {
+            ComputeMode::Single
+        } else {
+            ComputeMode::SimdMultiThread
+        }
+    }
+
     fn check_same_shape(&self, other: &Tensor) -> TensorResult<()> {
         if self.shape != other.shape {
             return Err(TensorError::ShapeMismatch(format!(
@@ -228,7 +252,7 @@ impl Tensor {
             }
             ComputeMode::MultiThread => {
                 // Check for zeros first
-                if other.data.iter().any(|&x| x == 0.0) {
+                if other.data.contains(&0.0) {
                     return Err(TensorError::InvalidOperation("Division by zero".to_string()));
                 }
                 result.data.par_iter_mut()
@@ -237,7 +261,7 @@ impl Tensor {
             }
             ComputeMode::SimdMultiThread => {
                 // Check for zeros first
-                if other.data.iter().any(|&x| x == 0.0) {
+                if other.data.contains(&0.0) {
                     return Err(TensorError::InvalidOperation("Division by zero".to_string()));
                 }
                 for i in 0..self.data.len() {
@@ -274,25 +298,43 @@ impl Tensor {
         let m = self.shape[0];
         let n = other.shape[1];
         let k = self.shape[1];
-        
+
+        if self.simd_processor.supports_avx2 {
+            return self.matrix_multiply_simd(other, m * n * k >= PARALLEL_THRESHOLD);
+        }
+
         let mut result = Tensor::zeros(&[m, n]);
         
         // Parallel matrix multiplication
         result.data.par_chunks_mut(n)
             .enumerate()
             .for_each(|(i, row)| {
-                for j in 0..n {
+                for (j, r) in row.iter_mut().enumerate() {
                     let mut sum = 0.0;
                     for l in 0..k {
                         sum += self.data[i * k + l] * other.data[l * n + j];
                     }
-                    row[j] = sum;
+                    *r = sum;
                 }
             });
 
         Ok(result)
     }
 
+    // AVX2 path shared with `ExecutionMode::ParallelSIMD`. Small products stay on one thread,
+    // since spawning a SIMDOps thread per rayon worker costs more than the multiply itself
+    fn matrix_multiply_simd(&self, other: &Tensor, parallel: bool) -> TensorResult<Tensor> {
+        let a = crate::tensor::Tensor::new(self.data.clone(), &self.shape)?;
+        let b = crate::tensor::Tensor::new(other.data.clone(), &other.shape)?;
+        let product = if parallel {
+            let nb_threads = rayon::current_num_threads().clamp(1, self.shape[0].max(1));
+            SIMDOps::matrix_multiply_parallel(&a, &b, nb_threads)?
+        } else {
+            SIMDOps::matrix_multiply(&a, &b)?
+        };
+        Tensor::new(product.into_data(), &[self.shape[0], other.shape[1]])
+    }
+
     fn vector_dot(&self, other: &Tensor) -> TensorResult<Tensor> {
         if self.shape[0] != other.shape[0] {
             return Err(TensorError::ShapeMismatch(format!(
@@ -374,20 +416,12 @@ impl Tensor {
                     .for_each(|(i, r)| *r = self.data[i] * scalar);
             }
             ComputeMode::SimdMultiThread => {
-                let scalar_vec = vec![scalar; self.data.len()];
                 let processor = Arc::clone(&self.simd_processor);
                 result.data.par_chunks_mut(self.chunk_size)
                     .enumerate()
                     .for_each(|(chunk_idx, chunk)| {
                         let start = chunk_idx * self.chunk_size;
-                        let end = std::cmp::min(start + chunk.len(), self.data.len());
-                        let len = end - start;
-                        
-                        processor.mul_slice(
-                            &self.data[start..start + len],
-                            &scalar_vec[start..start + len],
-                            &mut chunk[..len]
-                        );
+                        processor.scale_slice(&self.data[start..start + chunk.len()], scalar, chunk);
                     });
             }
         }
@@ -421,7 +455,7 @@ impl Add for &Tensor {
     type Output = TensorResult<Tensor>;
 
     fn add(self, other: &Tensor) -> Self::Output {
-        self.add(other, ComputeMode::SimdMultiThread)
+        self.add(other, self.auto_compute_mode())
     }
 }
 
@@ -429,7 +463,7 @@ impl Sub for &Tensor {
     type Output = TensorResult<Tensor>;
 
     fn sub(self, other: &Tensor) -> Self::Output {
-        self.sub(other, ComputeMode::SimdMultiThread)
+        self.sub(other, self.auto_compute_mode())
     }
 }
 
@@ -437,7 +471,7 @@ impl Mul for &Tensor {
     type Output = TensorResult<Tensor>;
 
     fn mul(self, other: &Tensor) -> Self::Output {
-        self.multiply(other, ComputeMode::SimdMultiThread)
+        self.multiply(other, self.auto_compute_mode())
     }
 }
 
@@ -445,7 +479,7 @@ impl Div for &Tensor {
     type Output = TensorResult<Tensor>;
 
     fn div(self, other: &Tensor) -> Self::Output {
-        self.divide(other, ComputeMode::SimdMultiThread)
+        self.divide(other, self.auto_compute_mode())
     }
 }
 